@@ -166,9 +166,9 @@ pub fn api_request_to_string(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, .. } = parse_macro_input!(input as DeriveInput);
 
     quote! {
-        impl ::std::string::ToString for #ident{
-            fn to_string(&self) ->String{
-                ::serde_qs::to_string(self).unwrap()
+        impl ::std::fmt::Display for #ident{
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result{
+                f.write_str(&::serde_qs::to_string(self).unwrap())
             }
         }
     }
@@ -275,6 +275,7 @@ fn parse_struct(ident: Ident, data: DataStruct) -> TokenStream {
 
             quote! {
                impl #ident {
+                   #[allow(clippy::too_many_arguments)]
                    pub fn init(#(#cons_args),*) -> Self {
                        #ident {
                            #(#required_field_names),*
@@ -7,6 +7,17 @@ pub struct ApiConfig {
     pub futures_rest_api_endpoint: String,
     pub futures_ws_endpoint: String,
 
+    pub coin_m_rest_api_endpoint: String,
+    pub coin_m_ws_endpoint: String,
+
+    /// `None` where the environment has no Portfolio Margin API
+    pub portfolio_margin_rest_api_endpoint: Option<String>,
+    pub portfolio_margin_ws_endpoint: Option<String>,
+
+    /// `None` where the environment has no options API
+    pub options_rest_api_endpoint: Option<String>,
+    pub options_ws_endpoint: Option<String>,
+
     pub recv_window: u64,
 }
 
@@ -18,7 +29,16 @@ impl Default for ApiConfig {
             ws_endpoint2: "wss://stream.binance.com:9443".into(),
 
             futures_rest_api_endpoint: "https://fapi.binance.com".into(),
-            futures_ws_endpoint: "wss://fstream.binance.com/ws".into(),
+            futures_ws_endpoint: "wss://fstream.binance.com".into(),
+
+            coin_m_rest_api_endpoint: "https://dapi.binance.com".into(),
+            coin_m_ws_endpoint: "wss://dstream.binance.com".into(),
+
+            portfolio_margin_rest_api_endpoint: Some("https://papi.binance.com".into()),
+            portfolio_margin_ws_endpoint: Some("wss://fstream.binance.com/pm".into()),
+
+            options_rest_api_endpoint: Some("https://eapi.binance.com".into()),
+            options_ws_endpoint: Some("wss://nbstream.binance.com/eoptions".into()),
 
             recv_window: 5000,
         }
@@ -26,12 +46,21 @@ impl Default for ApiConfig {
 }
 
 impl ApiConfig {
+    /// Binance has no testnet for Portfolio Margin or options, their
+    /// endpoints are `None` here.
     pub fn testnet() -> Self {
-        Self::default()
+        let mut config = Self::default()
             .set_rest_api_endpoint("https://testnet.binance.vision")
-            .set_ws_endpoint("wss://testnet.binance.vision/ws")
+            .set_ws_endpoint("wss://testnet.binance.vision")
             .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
-            .set_futures_ws_endpoint("https://testnet.binancefuture.com/ws")
+            .set_futures_ws_endpoint("wss://fstream.binancefuture.com")
+            .set_coin_m_rest_api_endpoint("https://testnet.binancefuture.com")
+            .set_coin_m_ws_endpoint("wss://dstream.binancefuture.com");
+        config.portfolio_margin_rest_api_endpoint = None;
+        config.portfolio_margin_ws_endpoint = None;
+        config.options_rest_api_endpoint = None;
+        config.options_ws_endpoint = None;
+        config
     }
 
     pub fn set_rest_api_endpoint<T: Into<String>>(mut self, rest_api_endpoint: T) -> Self {
@@ -56,6 +85,48 @@ impl ApiConfig {
         self
     }
 
+    pub fn set_coin_m_rest_api_endpoint<T: Into<String>>(
+        mut self,
+        coin_m_rest_api_endpoint: T,
+    ) -> Self {
+        self.coin_m_rest_api_endpoint = coin_m_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_coin_m_ws_endpoint<T: Into<String>>(mut self, coin_m_ws_endpoint: T) -> Self {
+        self.coin_m_ws_endpoint = coin_m_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_portfolio_margin_rest_api_endpoint<T: Into<String>>(
+        mut self,
+        portfolio_margin_rest_api_endpoint: T,
+    ) -> Self {
+        self.portfolio_margin_rest_api_endpoint = Some(portfolio_margin_rest_api_endpoint.into());
+        self
+    }
+
+    pub fn set_portfolio_margin_ws_endpoint<T: Into<String>>(
+        mut self,
+        portfolio_margin_ws_endpoint: T,
+    ) -> Self {
+        self.portfolio_margin_ws_endpoint = Some(portfolio_margin_ws_endpoint.into());
+        self
+    }
+
+    pub fn set_options_rest_api_endpoint<T: Into<String>>(
+        mut self,
+        options_rest_api_endpoint: T,
+    ) -> Self {
+        self.options_rest_api_endpoint = Some(options_rest_api_endpoint.into());
+        self
+    }
+
+    pub fn set_options_ws_endpoint<T: Into<String>>(mut self, options_ws_endpoint: T) -> Self {
+        self.options_ws_endpoint = Some(options_ws_endpoint.into());
+        self
+    }
+
     pub fn set_recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = recv_window;
         self
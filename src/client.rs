@@ -5,7 +5,9 @@ use reqwest::Client as rqwstClient;
 use serde::Deserialize;
 use sha2::Sha256;
 
-use crate::endpoints::{Endpoint, EndpointRequest, Method, Response};
+use crate::api_config::ApiConfig;
+use crate::endpoints::wallet::{ApiRestrictions, ApiRestrictionsRequest, WalletEP};
+use crate::endpoints::{BaseRequest, Endpoint, EndpointRequest, Method, Response};
 use crate::errors::*;
 
 #[derive(Clone)]
@@ -14,6 +16,7 @@ pub struct Client {
     secret_key: String,
     host: url::Url,
     inner_client: rqwstClient,
+    permissions: Option<ApiRestrictions>,
 }
 
 impl Client {
@@ -26,6 +29,7 @@ impl Client {
                 .pool_idle_timeout(None)
                 .build()
                 .unwrap(),
+            permissions: None,
         }
     }
 
@@ -33,15 +37,51 @@ impl Client {
         (&self.api_key, &self.secret_key)
     }
 
+    /// Fetches the permissions of the API key. From then on Trade/Margin
+    /// requests and withdrawals the key isn't allowed to make are refused
+    /// with `PermissionDenied` without being sent. The restrictions are only
+    /// served by the spot API, so they are fetched from
+    /// `config.rest_api_endpoint` whatever market this client is for.
+    pub async fn cache_permissions(&mut self, config: &ApiConfig) -> Result<&ApiRestrictions> {
+        let spot = Client {
+            host: url::Url::parse(&config.rest_api_endpoint)?,
+            permissions: None,
+            ..self.clone()
+        };
+        let restrictions = spot
+            .access::<ApiRestrictionsRequest>(
+                &WalletEP::ApiRestrictions,
+                Some(ApiRestrictionsRequest(BaseRequest::init())),
+            )
+            .await?;
+        Ok(self.permissions.insert(restrictions))
+    }
+
+    /// Uses restrictions fetched by another client of the same key.
+    pub fn set_permissions(&mut self, permissions: ApiRestrictions) {
+        self.permissions = Some(permissions);
+    }
+
+    pub fn clear_permissions(&mut self) {
+        self.permissions = None;
+    }
+
+    pub fn permissions(&self) -> Option<&ApiRestrictions> {
+        self.permissions.as_ref()
+    }
+
     pub async fn access<R: EndpointRequest>(
         &self,
         endpoint: &(dyn Endpoint + Send + Sync),
         data: Option<R>,
     ) -> Result<R::Response> {
         let (method, security, endpoint_path) = endpoint.action_params();
+        if let Some(permissions) = &self.permissions {
+            permissions.check(&security, &endpoint_path)?;
+        }
         let ds: String;
-        let query_str = if data.is_some() {
-            ds = data.unwrap().to_string();
+        let query_str = if let Some(data) = data {
+            ds = data.to_string();
             if ds.is_empty() {
                 None
             } else {
@@ -86,22 +126,44 @@ impl Client {
                 crate::endpoints::SecurityType::None => todo!(),
                 crate::endpoints::SecurityType::UserData
                 | crate::endpoints::SecurityType::Trade
-                | crate::endpoints::SecurityType::Margin => todo!(),
+                | crate::endpoints::SecurityType::Margin => {
+                    self.put_signed::<R::Response>(&endpoint_path, query_str)
+                        .await
+                }
                 crate::endpoints::SecurityType::UserStream
-                | crate::endpoints::SecurityType::MarketData => todo!(),
+                | crate::endpoints::SecurityType::MarketData => {
+                    self.put_key_only(&endpoint_path, query_str).await
+                }
             },
             Method::DELETE => match security {
                 crate::endpoints::SecurityType::None => todo!(),
                 crate::endpoints::SecurityType::UserData
                 | crate::endpoints::SecurityType::Trade
-                | crate::endpoints::SecurityType::Margin => todo!(),
+                | crate::endpoints::SecurityType::Margin => {
+                    self.delete_signed::<R::Response>(&endpoint_path, query_str)
+                        .await
+                }
                 crate::endpoints::SecurityType::UserStream
-                | crate::endpoints::SecurityType::MarketData => todo!(),
+                | crate::endpoints::SecurityType::MarketData => {
+                    self.delete_key_only(&endpoint_path, query_str).await
+                }
             },
             _ => unimplemented!(),
         }
     }
 
+    /// Fetches an absolute URL, such as a download link, as text.
+    pub(crate) async fn get_text(&self, url: &str) -> Result<String> {
+        Ok(self
+            .inner_client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?)
+    }
+
     pub async fn get<T>(&self, endpoint: &str, query_string: Option<&str>) -> Result<T>
     where
         T: for<'de> Deserialize<'de>,
@@ -243,6 +305,38 @@ impl Client {
         Self::handle_api_return(response).await
     }
 
+    pub async fn delete_key_only<T>(&self, endpoint: &str, data: Option<&str>) -> Result<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let mut url = self.host.join(endpoint)?;
+        url.set_query(data);
+        let response = self
+            .inner_client
+            .delete(url)
+            .headers(self.build_headers(false)?)
+            .send()
+            .await?;
+
+        Self::handle_api_return(response).await
+    }
+
+    pub async fn put_key_only<T>(&self, endpoint: &str, data: Option<&str>) -> Result<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let mut url = self.host.join(endpoint)?;
+        url.set_query(data);
+        let response = self
+            .inner_client
+            .put(url)
+            .headers(self.build_headers(false)?)
+            .send()
+            .await?;
+
+        Self::handle_api_return(response).await
+    }
+
     pub async fn put<T>(&self, endpoint: &str, listen_key: &str) -> Result<T>
     where
         T: for<'de> Deserialize<'de>,
@@ -260,6 +354,26 @@ impl Client {
         Self::handle_api_return(response).await
     }
 
+    pub async fn put_signed<T>(&self, endpoint: &str, request: Option<&str>) -> Result<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let mut url = self.host.join(endpoint)?;
+        url.set_query(request);
+        let sig_param = self.sign_request(url.query());
+        url.set_query(Some(&sig_param));
+
+        let response = self
+            .inner_client
+            .clone()
+            .put(url)
+            .headers(self.build_headers(false)?)
+            .send()
+            .await?;
+
+        Self::handle_api_return(response).await
+    }
+
     // Request must be signed
     fn sign_request(&self, request: Option<&str>) -> String {
         request.map_or_else(
@@ -314,6 +428,8 @@ impl Client {
         };
 
         match result {
+            // some endpoints acknowledge with a plain `{"code": 200, "msg": ...}`
+            Response::Error { code: 200, .. } => Ok(serde_json::from_str::<T>(&body)?),
             Response::Error { code, msg } => Err(BinanceApiError::ApiReturnError(code, msg)),
             Response::Data(t) => Ok(t),
         }
@@ -342,3 +458,26 @@ impl Client {
     // }
     // }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::futures_trading::{FuturesTradingEP, NewOrderRequest};
+
+    #[tokio::test]
+    async fn test_permissions() {
+        let json = r#"{"ipRestrict":false,"createTime":1698645219000,"enableReading":true,"enableWithdrawals":false,"enableInternalTransfer":true,"enableMargin":false,"enableFutures":false,"permitsUniversalTransfer":true,"enableVanillaOptions":false,"enableSpotAndMarginTrading":true,"enablePortfolioMarginTrading":false}"#;
+        let config = ApiConfig::default();
+        let mut futures = Client::new(None, None, &config.futures_rest_api_endpoint);
+        futures.set_permissions(serde_json::from_str(json).unwrap());
+
+        // refused before anything is sent
+        let result = futures
+            .access::<NewOrderRequest>(&FuturesTradingEP::Order, None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(BinanceApiError::PermissionDenied(path, "enableFutures")) if path == "/fapi/v1/order"
+        ));
+    }
+}
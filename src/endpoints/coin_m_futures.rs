@@ -1,12 +1,32 @@
-use crate::models::*;
+//! COIN-M (delivery) futures, served from `dapi.binance.com`.
+//!
+//! The endpoints mirror their USD-M `/fapi` counterparts, but quantities are
+//! counted in contracts: one contract is worth `contract_size` USD of the base
+//! asset, and the volumes reported in base asset are suffixed with `base`.
+//!
+//! Aggregate trades and the kline family have the same shape on both
+//! markets, use the `futures_market` requests with the `COIN_M_FutureEP`
+//! endpoints. Margin type, position mode, position margin, commission rate,
+//! ADL quantile and countdown cancel requests are shared with `futures_trading`
+//! and `futures_account` the same way.
+
 use crate::{
-    endpoints::{BaseRequest, Endpoint, SecurityType},
-    models::BnbBurnStatus,
+    endpoints::{
+        futures_market::{FuturesFilters, StatisticsPeriod},
+        futures_trading::{
+            serialize_batch, serialize_json_list, validate_order_id, validate_order_type,
+            FutureOrderType, MarginType, PositionSide, PriceMatch, ResponseType,
+            SelfTradePreventionMode, TimeInForce, WorkingType,
+        },
+        public_enums::OrderSide,
+        usd_m_futures::ContractStatus,
+        BaseRequest, Endpoint, Response, SecurityType,
+    },
+    models::{string_or_bool, Asks, Bids, RateLimit, Success, UserDataStream},
 };
 use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
 
-use serde::{Serialize, Deserialize};
-use serde_qs::to_string;
+use serde::{Deserialize, Serialize};
 
 use super::{EndpointRequest, OneOrMany};
 
@@ -14,29 +34,115 @@ pub enum SymbolType {
     Delivery,
     Perpetual,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractType {
     Perpetual,
     CurrentQuarter,
     NextQuarter,
+    #[serde(alias = "CURRENT_QUARTER DELIVERING")]
     CurrentQuarterDelivering,
+    #[serde(alias = "NEXT_QUARTER DELIVERING")]
     NextQuarterDelivering,
     PerpetualDelivering,
+    /// delisted symbols are reported with an empty contract type
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, APIEndPoint)]
-#[allow(dead_code)]
+#[allow(dead_code, non_camel_case_types)]
 pub enum COIN_M_FutureEP {
     #[endpoint(GET, MarketData, url = "/dapi/v1/ticker/price")]
     SymbolPriceTicker,
     #[endpoint(GET, MarketData, url = "/dapi/v1/ticker/bookTicker")]
     SymbolOrderBookTicker,
-    // CrossMarginPairs,
-    // #[endpoint(GET, UserData, url = "/sapi/v1/margin/crossMarginData")]
-    // CrossMarginFeeData,
-    // #[endpoint(GET, UserData, url = "/sapi/v1/margin/isolated/allPairs")]
-    // IsolatedMarginPairs,
-    // #[endpoint(GET, UserData, url = "/sapi/v1/margin/isolatedMarginData")]
-    // IsolatedMarginFeeData,
+    #[endpoint(GET, None, url = "/dapi/v1/exchangeInfo")]
+    ExchangeInfo,
+    #[endpoint(GET, None, url = "/dapi/v1/depth")]
+    OrderBook,
+    #[endpoint(GET, None, url = "/dapi/v1/trades")]
+    RecentTrades,
+    #[endpoint(GET, MarketData, url = "/dapi/v1/historicalTrades")]
+    HistoricalTrades,
+    #[endpoint(GET, None, url = "/dapi/v1/aggTrades")]
+    AggTrades,
+    #[endpoint(GET, None, url = "/dapi/v1/klines")]
+    Klines,
+    #[endpoint(GET, None, url = "/dapi/v1/continuousKlines")]
+    ContinuousKlines,
+    #[endpoint(GET, None, url = "/dapi/v1/indexPriceKlines")]
+    IndexPriceKlines,
+    #[endpoint(GET, None, url = "/dapi/v1/markPriceKlines")]
+    MarkPriceKlines,
+    #[endpoint(GET, None, url = "/dapi/v1/premiumIndex")]
+    PremiumIndex,
+    #[endpoint(GET, None, url = "/dapi/v1/fundingRate")]
+    FundingRateHistory,
+    #[endpoint(GET, None, url = "/dapi/v1/ticker/24hr")]
+    Ticker24hr,
+    #[endpoint(GET, None, url = "/dapi/v1/openInterest")]
+    OpenInterest,
+    #[endpoint(GET, None, url = "/futures/data/openInterestHist")]
+    OpenInterestHist,
+
+    #[endpoint(POST, Trade, url = "/dapi/v1/order")]
+    Order,
+    #[endpoint(PUT, Trade, url = "/dapi/v1/order")]
+    ModifyOrder,
+    #[endpoint(DELETE, Trade, url = "/dapi/v1/order")]
+    CancelOrder,
+    #[endpoint(GET, UserData, url = "/dapi/v1/order")]
+    QueryOrder,
+    #[endpoint(DELETE, Trade, url = "/dapi/v1/allOpenOrders")]
+    CancelAllOpenOrders,
+    #[endpoint(GET, UserData, url = "/dapi/v1/openOrders")]
+    OpenOrders,
+    #[endpoint(GET, UserData, url = "/dapi/v1/allOrders")]
+    AllOrders,
+    #[endpoint(POST, Trade, url = "/dapi/v1/batchOrders")]
+    BatchOrders,
+    #[endpoint(PUT, Trade, url = "/dapi/v1/batchOrders")]
+    ModifyBatchOrders,
+    #[endpoint(DELETE, Trade, url = "/dapi/v1/batchOrders")]
+    CancelBatchOrders,
+    #[endpoint(POST, Trade, url = "/dapi/v1/countdownCancelAll")]
+    CountdownCancelAll,
+    #[endpoint(POST, Trade, url = "/dapi/v1/leverage")]
+    Leverage,
+    #[endpoint(POST, Trade, url = "/dapi/v1/marginType")]
+    MarginType,
+    #[endpoint(POST, Trade, url = "/dapi/v1/positionSide/dual")]
+    ChangePositionMode,
+    #[endpoint(GET, UserData, url = "/dapi/v1/positionSide/dual")]
+    PositionMode,
+    #[endpoint(POST, Trade, url = "/dapi/v1/positionMargin")]
+    PositionMargin,
+    #[endpoint(GET, UserData, url = "/dapi/v1/positionMargin/history")]
+    PositionMarginHistory,
+    #[endpoint(GET, UserData, url = "/dapi/v1/positionRisk")]
+    PositionRisk,
+    #[endpoint(GET, UserData, url = "/dapi/v1/userTrades")]
+    UserTrades,
+
+    #[endpoint(GET, UserData, url = "/dapi/v1/account")]
+    Account,
+    #[endpoint(GET, UserData, url = "/dapi/v1/balance")]
+    Balance,
+    #[endpoint(GET, UserData, url = "/dapi/v1/commissionRate")]
+    CommissionRate,
+    #[endpoint(GET, UserData, url = "/dapi/v2/leverageBracket")]
+    LeverageBracket,
+    #[endpoint(GET, UserData, url = "/dapi/v1/adlQuantile")]
+    AdlQuantile,
+
+    #[endpoint(POST, UserStream, url = "/dapi/v1/listenKey")]
+    StartUserDataStream,
+    #[endpoint(PUT, UserStream, url = "/dapi/v1/listenKey")]
+    KeepaliveUserDataStream,
+    #[endpoint(DELETE, UserStream, url = "/dapi/v1/listenKey")]
+    CloseUserDataStream,
 }
 
 #[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
@@ -61,3 +167,777 @@ impl EndpointRequest for SymbolPriceTickerRequest {
         Ok(())
     }
 }
+
+fn validate_symbol_or_pair(symbol: Option<&str>, pair: Option<&str>) -> anyhow::Result<()> {
+    if symbol.is_some() && pair.is_some() {
+        anyhow::bail!("symbol and pair can not be sent at the same time")
+    }
+    Ok(())
+}
+
+#[derive(Debug, APIRequestInit, APIRequestToString, Serialize, Deserialize)]
+pub struct ExchangeInfoRequest {}
+
+impl EndpointRequest for ExchangeInfoRequest {
+    type Response = ExchangeInformation;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub symbols: Vec<Symbol>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: ContractStatus,
+    /// USD value of one contract
+    pub contract_size: u32,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub margin_asset: String,
+    pub price_precision: u32,
+    pub quantity_precision: u32,
+    pub base_asset_precision: u32,
+    pub quote_precision: u32,
+    pub equal_qty_precision: u32,
+    pub underlying_type: String,
+    #[serde(default)]
+    pub underlying_sub_type: Vec<String>,
+    pub trigger_protect: String,
+    pub liquidation_fee: String,
+    pub market_take_bound: String,
+    pub filters: Vec<FuturesFilters>,
+    #[serde(alias = "OrderType")]
+    pub order_types: Vec<FutureOrderType>,
+    pub time_in_force: Vec<TimeInForce>,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBookRequest {
+    pub symbol: String,
+    /// 5, 10, 20, 50, 100, 500, 1000
+    pub limit: Option<u16>,
+}
+
+impl EndpointRequest for OrderBookRequest {
+    type Response = OrderBook;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBook {
+    pub last_update_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    #[serde(rename = "E")]
+    pub message_output_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentTradesRequest {
+    pub symbol: String,
+    pub limit: Option<u16>,
+}
+
+impl EndpointRequest for RecentTradesRequest {
+    type Response = Vec<Trade>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalTradesRequest {
+    pub symbol: String,
+    pub limit: Option<u16>,
+    pub from_id: Option<u64>,
+}
+
+impl EndpointRequest for HistoricalTradesRequest {
+    type Response = Vec<Trade>;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub price: String,
+    /// contracts
+    pub qty: String,
+    pub base_qty: String,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndexRequest {
+    pub symbol: Option<String>,
+    pub pair: Option<String>,
+}
+
+impl EndpointRequest for PremiumIndexRequest {
+    type Response = Vec<PremiumIndex>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_symbol_or_pair(self.symbol.as_deref(), self.pair.as_deref())
+    }
+}
+
+/// Funding fields are empty for delivery contracts.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndex {
+    pub symbol: String,
+    pub pair: String,
+    pub mark_price: String,
+    pub index_price: String,
+    pub estimated_settle_price: String,
+    pub last_funding_rate: String,
+    pub interest_rate: String,
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistoryRequest {
+    pub symbol: String,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+
+impl EndpointRequest for FundingRateHistoryRequest {
+    type Response = Vec<FundingRate>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.limit.is_some_and(|limit| limit > 1000) {
+            anyhow::bail!("limit max 1000")
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    pub funding_time: u64,
+    pub funding_rate: String,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker24hrRequest {
+    pub symbol: Option<String>,
+    pub pair: Option<String>,
+}
+
+impl EndpointRequest for Ticker24hrRequest {
+    type Response = Vec<PriceStats>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_symbol_or_pair(self.symbol.as_deref(), self.pair.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceStats {
+    pub symbol: String,
+    pub pair: String,
+    pub price_change: String,
+    pub price_change_percent: String,
+    pub weighted_avg_price: String,
+    pub last_price: String,
+    pub last_qty: String,
+    pub open_price: String,
+    pub high_price: String,
+    pub low_price: String,
+    /// contracts
+    pub volume: String,
+    pub base_volume: String,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestRequest {
+    pub symbol: String,
+}
+
+impl EndpointRequest for OpenInterestRequest {
+    type Response = OpenInterest;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    pub pair: String,
+    /// contracts
+    pub open_interest: String,
+    pub contract_type: ContractType,
+    pub time: u64,
+}
+
+/// Only the data of the latest 30 days is available.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHistRequest {
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub period: StatisticsPeriod,
+    pub limit: Option<u16>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
+impl EndpointRequest for OpenInterestHistRequest {
+    type Response = Vec<OpenInterestHist>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.limit.is_some_and(|limit| limit > 500) {
+            anyhow::bail!("limit max 500")
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHist {
+    pub pair: String,
+    pub contract_type: ContractType,
+    /// contracts
+    pub sum_open_interest: String,
+    /// in base asset
+    pub sum_open_interest_value: String,
+    pub timestamp: u64,
+}
+
+/// Same as the USD-M order, but `quantity` is a number of contracts.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub position_side: Option<PositionSide>,
+    pub r#type: FutureOrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<u64>,
+    pub reduce_only: Option<bool>,
+    pub price: Option<f64>,
+    pub new_client_order_id: Option<String>,
+    pub stop_price: Option<f64>,
+    pub close_position: Option<String>,
+    pub activation_price: Option<f64>,
+    pub callback_rate: Option<f64>,
+    pub working_type: Option<WorkingType>,
+    pub price_protect: Option<String>,
+    pub new_order_resp_type: Option<ResponseType>,
+    pub price_match: Option<PriceMatch>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for NewOrderRequest {
+    type Response = OrderResponse;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_order_type(
+            self.r#type,
+            self.quantity.is_some(),
+            self.time_in_force.is_some(),
+            self.price.is_some(),
+            self.stop_price.is_some(),
+            self.callback_rate.is_some(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResponse {
+    pub client_order_id: String,
+    /// not returned when querying orders
+    #[serde(default)]
+    pub cum_qty: String,
+    pub cum_base: String,
+    pub executed_qty: String,
+    pub order_id: u64,
+    pub avg_price: String,
+    pub orig_qty: String,
+    pub price: String,
+    pub reduce_only: bool,
+    pub side: String,
+    pub position_side: String,
+    pub status: String,
+    pub stop_price: String,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: String,
+    pub r#type: String,
+    pub orig_type: String,
+    pub activate_price: Option<String>,
+    pub price_rate: Option<String>,
+    pub update_time: u64,
+    pub working_type: String,
+    pub price_protect: bool,
+    pub price_match: Option<String>,
+    pub self_trade_prevention_mode: Option<String>,
+    /// only returned when querying orders
+    pub time: Option<u64>,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: u64,
+    /// exactly one of `price` and `price_match` must be sent
+    pub price: Option<f64>,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub price_match: Option<PriceMatch>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for ModifyOrderRequest {
+    type Response = OrderResponse;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.price.is_some() == self.price_match.is_some() {
+            anyhow::bail!("exactly one of price and price_match must be sent")
+        }
+        validate_order_id(self.order_id, self.orig_client_order_id.as_deref())
+    }
+}
+
+/// Shared by cancel and query order.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderIdRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for OrderIdRequest {
+    type Response = OrderResponse;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_order_id(self.order_id, self.orig_client_order_id.as_deref())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersRequest {
+    pub symbol: Option<String>,
+    pub pair: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for OpenOrdersRequest {
+    type Response = Vec<OrderResponse>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOrdersRequest {
+    pub symbol: Option<String>,
+    pub pair: Option<String>,
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for AllOrdersRequest {
+    type Response = Vec<OrderResponse>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.symbol.is_none() && self.pair.is_none() {
+            anyhow::bail!("either symbol or pair must be sent")
+        }
+        if self.limit.is_some_and(|limit| limit > 100) {
+            anyhow::bail!("limit max 100")
+        }
+        Ok(())
+    }
+}
+
+/// The orders are sent as a JSON list, at most 5 per request.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrdersRequest {
+    #[serde(serialize_with = "serialize_batch")]
+    pub batch_orders: Vec<NewOrderRequest>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for BatchOrdersRequest {
+    type Response = Vec<Response<OrderResponse>>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.batch_orders.is_empty() || self.batch_orders.len() > 5 {
+            anyhow::bail!("batch_orders takes 1 to 5 orders")
+        }
+        self.batch_orders
+            .iter()
+            .try_for_each(|order| order.validate())
+    }
+}
+
+/// The modifications are sent as a JSON list, at most 5 per request.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyBatchOrdersRequest {
+    #[serde(serialize_with = "serialize_batch")]
+    pub batch_orders: Vec<ModifyOrderRequest>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for ModifyBatchOrdersRequest {
+    type Response = Vec<Response<OrderResponse>>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.batch_orders.is_empty() || self.batch_orders.len() > 5 {
+            anyhow::bail!("batch_orders takes 1 to 5 orders")
+        }
+        self.batch_orders
+            .iter()
+            .try_for_each(|order| order.validate())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelBatchOrdersRequest {
+    pub symbol: String,
+    #[serde(serialize_with = "serialize_json_list")]
+    pub order_id_list: Option<Vec<u64>>,
+    #[serde(serialize_with = "serialize_json_list")]
+    pub orig_client_order_id_list: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for CancelBatchOrdersRequest {
+    type Response = Vec<Response<OrderResponse>>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        match (&self.order_id_list, &self.orig_client_order_id_list) {
+            (Some(ids), None) if !ids.is_empty() && ids.len() <= 10 => Ok(()),
+            (None, Some(ids)) if !ids.is_empty() && ids.len() <= 10 => Ok(()),
+            _ => anyhow::bail!(
+                "exactly one of order_id_list and orig_client_order_id_list with 1 to 10 ids"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageRequest {
+    pub symbol: String,
+    pub leverage: i32,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageResponse {
+    pub leverage: i32,
+    /// in contracts
+    pub max_qty: String,
+    pub symbol: String,
+}
+
+impl EndpointRequest for LeverageRequest {
+    type Response = LeverageResponse;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRiskRequest {
+    pub margin_asset: Option<String>,
+    pub pair: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for PositionRiskRequest {
+    type Response = Vec<PositionRisk>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: String,
+    /// contracts
+    pub position_amt: String,
+    pub entry_price: String,
+    pub break_even_price: String,
+    pub mark_price: String,
+    pub un_realized_profit: String,
+    pub liquidation_price: String,
+    pub leverage: String,
+    pub max_qty: String,
+    pub margin_type: MarginType,
+    pub isolated_margin: String,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub position_side: PositionSide,
+    pub notional_value: String,
+    pub isolated_wallet: String,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTradesRequest {
+    pub symbol: Option<String>,
+    pub pair: Option<String>,
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub from_id: Option<u64>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for UserTradesRequest {
+    type Response = Vec<UserTrade>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.symbol.is_some() == self.pair.is_some() {
+            anyhow::bail!("exactly one of symbol and pair must be sent")
+        }
+        if self.from_id.is_some() && self.pair.is_some() {
+            anyhow::bail!("from_id can not be used with pair")
+        }
+        if self.limit.is_some_and(|limit| limit > 1000) {
+            anyhow::bail!("limit max 1000")
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub pair: String,
+    pub side: OrderSide,
+    pub price: String,
+    /// contracts
+    pub qty: String,
+    pub realized_pnl: String,
+    pub margin_asset: String,
+    pub base_qty: String,
+    pub commission: String,
+    pub commission_asset: String,
+    pub time: u64,
+    pub position_side: PositionSide,
+    pub buyer: bool,
+    pub maker: bool,
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct AccountRequest(pub BaseRequest);
+
+impl EndpointRequest for AccountRequest {
+    type Response = AccountInformation;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub assets: Vec<AccountAsset>,
+    pub positions: Vec<AccountPosition>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u32,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    pub wallet_balance: String,
+    pub unrealized_profit: String,
+    pub margin_balance: String,
+    pub maint_margin: String,
+    pub initial_margin: String,
+    pub position_initial_margin: String,
+    pub open_order_initial_margin: String,
+    pub max_withdraw_amount: String,
+    pub cross_wallet_balance: String,
+    pub cross_un_pnl: String,
+    pub available_balance: String,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    pub symbol: String,
+    /// contracts
+    pub position_amt: String,
+    pub initial_margin: String,
+    pub maint_margin: String,
+    pub unrealized_profit: String,
+    pub position_initial_margin: String,
+    pub open_order_initial_margin: String,
+    pub leverage: String,
+    pub isolated: bool,
+    pub position_side: PositionSide,
+    pub entry_price: String,
+    pub max_qty: String,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct BalanceRequest(pub BaseRequest);
+
+impl EndpointRequest for BalanceRequest {
+    type Response = Vec<Balance>;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    pub account_alias: String,
+    pub asset: String,
+    pub balance: String,
+    pub withdraw_available: String,
+    pub cross_wallet_balance: String,
+    pub cross_un_pnl: String,
+    pub available_balance: String,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracketRequest {
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for LeverageBracketRequest {
+    type Response = Vec<SymbolLeverageBrackets>;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolLeverageBrackets {
+    pub symbol: String,
+    pub brackets: Vec<LeverageBracket>,
+}
+
+/// The caps and floors are expressed in base asset.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracket {
+    pub bracket: u32,
+    pub initial_leverage: u32,
+    pub qty_cap: f64,
+    pub qty_floor: f64,
+    pub maint_margin_ratio: f64,
+    pub cum: f64,
+}
+
+/// Starts or keeps alive the user data stream, both return the listen key.
+#[derive(Debug, APIRequestInit, APIRequestToString, Serialize, Deserialize)]
+pub struct ListenKeyRequest {}
+
+impl EndpointRequest for ListenKeyRequest {
+    type Response = UserDataStream;
+}
+
+#[derive(Debug, APIRequestInit, APIRequestToString, Serialize, Deserialize)]
+pub struct CloseListenKeyRequest {}
+
+impl EndpointRequest for CloseListenKeyRequest {
+    type Response = Success;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_type() {
+        let contract_type: ContractType =
+            serde_json::from_str(r#""CURRENT_QUARTER DELIVERING""#).unwrap();
+        assert_eq!(contract_type, ContractType::CurrentQuarterDelivering);
+        let contract_type: ContractType = serde_json::from_str(r#""""#).unwrap();
+        assert_eq!(contract_type, ContractType::Unknown);
+    }
+
+    #[test]
+    fn test_order_response() {
+        let json = r#"{"avgPrice":"0.0","clientOrderId":"abc","cumBase":"0","executedQty":"0","orderId":1917641,"origQty":"0.40","origType":"TRAILING_STOP_MARKET","price":"0","reduceOnly":false,"side":"BUY","positionSide":"SHORT","status":"NEW","stopPrice":"9300","closePosition":false,"symbol":"BTCUSD_200925","pair":"BTCUSD","time":1579276756075,"timeInForce":"GTC","type":"TRAILING_STOP_MARKET","activatePrice":"9020","priceRate":"0.3","updateTime":1579276756075,"workingType":"CONTRACT_PRICE","priceProtect":false}"#;
+        let order: OrderResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(order.pair, "BTCUSD");
+        assert_eq!(order.time, Some(1579276756075));
+    }
+
+    #[test]
+    fn test_modify_order() {
+        let mut modify = ModifyOrderRequest::init(
+            "BTCUSD_PERP".to_string(),
+            OrderSide::Sell,
+            2,
+            BaseRequest::init(),
+        );
+        modify.order_id = Some(1);
+        assert!(modify.validate().is_err());
+        modify.price = Some(60000.0);
+        assert!(modify.validate().is_ok());
+        assert!(modify
+            .to_string()
+            .starts_with("symbol=BTCUSD_PERP&side=SELL&quantity=2&price=60000&orderId=1&"));
+        modify.price_match = Some(PriceMatch::Opponent);
+        assert!(modify.validate().is_err());
+    }
+}
@@ -1,15 +1,30 @@
 #![allow(dead_code)]
 
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
 use super::{BaseRequest, Endpoint, EndpointRequest, SecurityType};
-use crate::models::CoinFraction;
-use serde::Serialize;
+use crate::client::Client;
+use crate::errors::{BinanceApiError, Result};
+use crate::models::{string_or_float, CoinFraction};
+use crate::utils::floor_to_decimals;
+use serde::{Deserialize, Serialize};
 
-use binance_api_macros::{APIEndPoint, APIRequestToString};
+use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
 
 #[derive(Debug, APIEndPoint)]
 pub enum ConvertEP {
     #[endpoint(GET, UserData, url = "/sapi/v1/convert/assetInfo")]
     AssetInfo,
+    #[endpoint(GET, MarketData, url = "/sapi/v1/convert/exchangeInfo")]
+    ExchangeInfo,
+    #[endpoint(POST, UserData, url = "/sapi/v1/convert/getQuote")]
+    GetQuote,
+    #[endpoint(POST, Trade, url = "/sapi/v1/convert/acceptQuote")]
+    AcceptQuote,
+    #[endpoint(GET, UserData, url = "/sapi/v1/convert/orderStatus")]
+    OrderStatus,
+    #[endpoint(GET, UserData, url = "/sapi/v1/convert/tradeFlow")]
+    TradeFlow,
 }
 
 #[derive(Debug, Serialize, APIRequestToString)]
@@ -17,3 +32,330 @@ pub struct AssetInfoRequest(pub BaseRequest);
 impl EndpointRequest for AssetInfoRequest {
     type Response = Vec<CoinFraction>;
 }
+
+/// Either asset may be omitted to list all pairs of the other one.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfoRequest {
+    pub from_asset: Option<String>,
+    pub to_asset: Option<String>,
+}
+impl EndpointRequest for ExchangeInfoRequest {
+    type Response = Vec<ConvertPair>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.from_asset.is_none() && self.to_asset.is_none() {
+            anyhow::bail!("either from_asset or to_asset must be sent");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertPair {
+    pub from_asset: String,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_asset_min_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub from_asset_max_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub to_asset_min_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub to_asset_max_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConvertWalletType {
+    Spot,
+    Funding,
+    SpotFunding,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteValidTime {
+    #[serde(rename = "10s")]
+    TenSeconds,
+    #[serde(rename = "30s")]
+    ThirtySeconds,
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "2m")]
+    TwoMinutes,
+}
+
+/// Exactly one of from_amount and to_amount must be sent.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct GetQuoteRequest {
+    pub from_asset: String,
+    pub to_asset: String,
+    pub from_amount: Option<f64>,
+    pub to_amount: Option<f64>,
+    pub wallet_type: Option<ConvertWalletType>,
+    pub valid_time: Option<QuoteValidTime>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for GetQuoteRequest {
+    type Response = Quote;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.from_amount.is_some() == self.to_amount.is_some() {
+            anyhow::bail!("exactly one of from_amount and to_amount must be sent");
+        }
+        Ok(())
+    }
+}
+
+/// `ratio` is the amount of to_asset received per from_asset,
+/// `inverse_ratio` the amount of from_asset paid per to_asset.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Quote {
+    pub quote_id: String,
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    pub valid_timestamp: u64,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptQuoteRequest {
+    pub quote_id: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for AcceptQuoteRequest {
+    type Response = AcceptedQuote;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConvertOrderStatus {
+    Process,
+    AcceptSuccess,
+    Success,
+    Fail,
+}
+
+impl ConvertOrderStatus {
+    pub fn is_final(self) -> bool {
+        matches!(self, ConvertOrderStatus::Success | ConvertOrderStatus::Fail)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptedQuote {
+    pub order_id: String,
+    pub create_time: u64,
+    pub order_status: ConvertOrderStatus,
+}
+
+/// Either order_id or quote_id must be sent.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderStatusRequest {
+    pub order_id: Option<String>,
+    pub quote_id: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for OrderStatusRequest {
+    type Response = ConvertOrder;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.order_id.is_none() && self.quote_id.is_none() {
+            anyhow::bail!("either order_id or quote_id must be sent");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertOrder {
+    pub quote_id: Option<String>,
+    pub order_id: u64,
+    pub order_status: ConvertOrderStatus,
+    pub from_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    pub create_time: u64,
+}
+
+/// The time window can't be longer than 30 days.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFlowRequest {
+    pub start_time: u64,
+    pub end_time: u64,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for TradeFlowRequest {
+    type Response = TradeFlow;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.end_time.saturating_sub(self.start_time) > 30 * 24 * 60 * 60 * 1000 {
+            anyhow::bail!("the time window can't be longer than 30 days");
+        }
+        if self.limit.is_some_and(|l| l > 1000) {
+            anyhow::bail!("limit can't be greater than 1000");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFlow {
+    pub list: Vec<ConvertOrder>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub limit: u32,
+    pub more_data: bool,
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+/// Converts `from_amount` of `from_asset` into `to_asset`.
+///
+/// The amount is rounded down to the precision returned by `assetInfo`. The
+/// quote is only accepted if its price, i.e. the amount of `from_asset` paid
+/// per `to_asset`, is at most `max_price` and it is still valid. The order is
+/// then polled every `poll_interval` until it succeeds or fails, for at most
+/// `max_wait`.
+pub async fn convert(
+    client: &Client,
+    from_asset: &str,
+    to_asset: &str,
+    from_amount: f64,
+    max_price: f64,
+    poll_interval: Duration,
+    max_wait: Duration,
+) -> Result<ConvertOrder> {
+    let fractions = client
+        .access::<AssetInfoRequest>(
+            &ConvertEP::AssetInfo,
+            Some(AssetInfoRequest(BaseRequest::init())),
+        )
+        .await?;
+    let fraction = fractions
+        .iter()
+        .find(|f| f.asset == from_asset)
+        .ok_or_else(|| BinanceApiError::AssetNotFound(from_asset.to_string()))?
+        .fraction;
+    // never spend more than requested
+    let amount = floor_to_decimals(from_amount, fraction);
+    if amount <= 0.0 {
+        return Err(BinanceApiError::Custom(format!(
+            "{from_amount} {from_asset} rounds to zero at {fraction} decimals"
+        )));
+    }
+
+    let mut req = GetQuoteRequest::init(
+        from_asset.to_string(),
+        to_asset.to_string(),
+        BaseRequest::init(),
+    );
+    req.from_amount = Some(amount);
+    let quote = client
+        .access::<GetQuoteRequest>(&ConvertEP::GetQuote, Some(req))
+        .await?;
+    if quote.inverse_ratio > max_price {
+        return Err(BinanceApiError::Custom(format!(
+            "quote price {} {from_asset}/{to_asset} is above {max_price}",
+            quote.inverse_ratio
+        )));
+    }
+    if now_ms() >= quote.valid_timestamp {
+        return Err(BinanceApiError::Custom(format!(
+            "quote {} expired before it could be accepted",
+            quote.quote_id
+        )));
+    }
+
+    let accepted = client
+        .access::<AcceptQuoteRequest>(
+            &ConvertEP::AcceptQuote,
+            Some(AcceptQuoteRequest::init(
+                quote.quote_id,
+                BaseRequest::init(),
+            )),
+        )
+        .await?;
+
+    let started = Instant::now();
+    loop {
+        let mut req = OrderStatusRequest::init(BaseRequest::init());
+        req.order_id = Some(accepted.order_id.clone());
+        let order = client
+            .access::<OrderStatusRequest>(&ConvertEP::OrderStatus, Some(req))
+            .await?;
+        match order.order_status {
+            ConvertOrderStatus::Success => return Ok(order),
+            ConvertOrderStatus::Fail => {
+                return Err(BinanceApiError::Custom(format!(
+                    "convert order {} failed",
+                    order.order_id
+                )))
+            }
+            _ => {}
+        }
+        if started.elapsed() + poll_interval > max_wait {
+            return Err(BinanceApiError::Custom(format!(
+                "convert order {} not final after {max_wait:?}",
+                accepted.order_id
+            )));
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote() {
+        let mut req = GetQuoteRequest::init("USDT".into(), "BTC".into(), BaseRequest::init());
+        assert!(req.validate().is_err());
+        req.from_amount = Some(100.0);
+        req.valid_time = Some(QuoteValidTime::OneMinute);
+        assert!(req.validate().is_ok());
+        assert!(req
+            .to_string()
+            .starts_with("fromAsset=USDT&toAsset=BTC&fromAmount=100&validTime=1m&timestamp="));
+
+        let json = r#"{"quoteId":"12415572564","ratio":"38163.7","inverseRatio":"0.0000262","validTimestamp":1623319461670,"toAmount":"3816.37","fromAmount":"0.1"}"#;
+        let quote: Quote = serde_json::from_str(json).unwrap();
+        assert_eq!(quote.ratio, 38163.7);
+
+        let json = r#"{"orderId":933256278426274426,"orderStatus":"SUCCESS","fromAsset":"BTC","fromAmount":"0.00054414","toAsset":"USDT","toAmount":"20","ratio":"36755","inverseRatio":"0.00002721","createTime":1623381330472}"#;
+        let order: ConvertOrder = serde_json::from_str(json).unwrap();
+        assert!(order.order_status.is_final());
+    }
+}
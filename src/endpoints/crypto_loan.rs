@@ -0,0 +1,403 @@
+#![allow(dead_code)]
+
+use super::{BaseRequest, Endpoint, EndpointRequest, SecurityType};
+use crate::models::string_or_float;
+use serde::{Deserialize, Serialize};
+
+use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
+
+#[derive(Debug, APIEndPoint)]
+pub enum CryptoLoanEP {
+    #[endpoint(GET, UserData, url = "/sapi/v2/loan/flexible/loanable/data")]
+    LoanableData,
+    #[endpoint(GET, UserData, url = "/sapi/v2/loan/flexible/collateral/data")]
+    CollateralData,
+    #[endpoint(POST, Trade, url = "/sapi/v2/loan/flexible/borrow")]
+    Borrow,
+    #[endpoint(POST, Trade, url = "/sapi/v2/loan/flexible/repay")]
+    Repay,
+    #[endpoint(POST, Trade, url = "/sapi/v2/loan/flexible/adjust/ltv")]
+    AdjustLtv,
+    #[endpoint(GET, UserData, url = "/sapi/v2/loan/flexible/ongoing/orders")]
+    OngoingOrders,
+    #[endpoint(GET, UserData, url = "/sapi/v2/loan/flexible/borrow/history")]
+    BorrowHistory,
+    #[endpoint(GET, UserData, url = "/sapi/v2/loan/flexible/repay/history")]
+    RepayHistory,
+    #[endpoint(GET, UserData, url = "/sapi/v2/loan/flexible/ltv/adjustment/history")]
+    LtvAdjustmentHistory,
+}
+
+/// Paged response of the data, order and history endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanPage<T> {
+    #[serde(default = "Vec::new")]
+    pub rows: Vec<T>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanableDataRequest {
+    pub loan_coin: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for LoanableDataRequest {
+    type Response = LoanPage<LoanableAsset>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanableAsset {
+    pub loan_coin: String,
+    /// annual rate
+    #[serde(with = "string_or_float")]
+    pub flexible_interest_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub flexible_min_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub flexible_max_limit: f64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralDataRequest {
+    pub collateral_coin: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for CollateralDataRequest {
+    type Response = LoanPage<CollateralAsset>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralAsset {
+    pub collateral_coin: String,
+    #[serde(rename = "initialLTV", with = "string_or_float")]
+    pub initial_ltv: f64,
+    #[serde(rename = "marginCallLTV", with = "string_or_float")]
+    pub margin_call_ltv: f64,
+    #[serde(rename = "liquidationLTV", with = "string_or_float")]
+    pub liquidation_ltv: f64,
+    #[serde(with = "string_or_float")]
+    pub max_limit: f64,
+}
+
+/// Exactly one of `loan_amount` and `collateral_amount` must be sent, the
+/// other one is derived from the initial LTV.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanBorrowRequest {
+    pub loan_coin: String,
+    pub loan_amount: Option<f64>,
+    pub collateral_coin: String,
+    pub collateral_amount: Option<f64>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for LoanBorrowRequest {
+    type Response = LoanBorrow;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.loan_amount.is_some() == self.collateral_amount.is_some() {
+            anyhow::bail!("exactly one of loan_amount and collateral_amount must be sent");
+        }
+        Ok(())
+    }
+}
+
+/// Status of borrows and LTV adjustments.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum LoanStatus {
+    Succeeds,
+    Failed,
+    Processing,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanBorrow {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub loan_amount: f64,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    pub status: LoanStatus,
+}
+
+/// Repayment source, repaying with collateral needs `full_repayment`
+/// unset or false.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoanRepaymentType {
+    LoanCoin,
+    Collateral,
+}
+
+impl Serialize for LoanRepaymentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(match self {
+            LoanRepaymentType::LoanCoin => 1,
+            LoanRepaymentType::Collateral => 2,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanRepayRequest {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    pub repay_amount: f64,
+    /// return the freed collateral, default true
+    pub collateral_return: Option<bool>,
+    pub full_repayment: Option<bool>,
+    pub repayment_type: Option<LoanRepaymentType>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for LoanRepayRequest {
+    type Response = LoanRepay;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum LoanRepayStatus {
+    Repaid,
+    Repaying,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanRepay {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub remaining_debt: f64,
+    #[serde(with = "string_or_float")]
+    pub remaining_collateral: f64,
+    pub full_repayment: bool,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    pub repay_status: LoanRepayStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LtvDirection {
+    /// add collateral
+    Additional,
+    /// take collateral back
+    Reduced,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AdjustLtvRequest {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    pub adjustment_amount: f64,
+    pub direction: LtvDirection,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for AdjustLtvRequest {
+    type Response = LtvAdjustment;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LtvAdjustment {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    pub direction: LtvDirection,
+    #[serde(with = "string_or_float")]
+    pub adjustment_amount: f64,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    pub status: LoanStatus,
+}
+
+/// `limit` is at most 100.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OngoingOrdersRequest {
+    pub loan_coin: Option<String>,
+    pub collateral_coin: Option<String>,
+    pub current: Option<u32>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for OngoingOrdersRequest {
+    type Response = LoanPage<OngoingLoan>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.limit.is_some_and(|l| l > 100) {
+            anyhow::bail!("limit must be at most 100");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OngoingLoan {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub total_debt: f64,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+}
+
+/// Shared by the borrow, repay and LTV adjustment histories. Without
+/// `start_time` the last 90 days are returned, `limit` is at most 100.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanHistoryRequest {
+    pub loan_coin: Option<String>,
+    pub collateral_coin: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl LoanHistoryRequest {
+    fn check_limit(&self) -> anyhow::Result<()> {
+        if self.limit.is_some_and(|l| l > 100) {
+            anyhow::bail!("limit must be at most 100");
+        }
+        Ok(())
+    }
+}
+
+/// Typed wrappers so each history endpoint gets its own response type.
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct BorrowHistoryRequest(pub LoanHistoryRequest);
+impl EndpointRequest for BorrowHistoryRequest {
+    type Response = LoanPage<BorrowRecord>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        self.0.check_limit()
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct RepayHistoryRequest(pub LoanHistoryRequest);
+impl EndpointRequest for RepayHistoryRequest {
+    type Response = LoanPage<RepayRecord>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        self.0.check_limit()
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct LtvAdjustmentHistoryRequest(pub LoanHistoryRequest);
+impl EndpointRequest for LtvAdjustmentHistoryRequest {
+    type Response = LoanPage<LtvAdjustmentRecord>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        self.0.check_limit()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRecord {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub initial_loan_amount: f64,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub initial_collateral_amount: f64,
+    pub borrow_time: u64,
+    pub status: LoanStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RepayRecord {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub repay_amount: f64,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub collateral_return: f64,
+    pub repay_status: LoanRepayStatus,
+    pub repay_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LtvAdjustmentRecord {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    pub direction: LtvDirection,
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    #[serde(rename = "preLTV", with = "string_or_float")]
+    pub pre_ltv: f64,
+    #[serde(rename = "afterLTV", with = "string_or_float")]
+    pub after_ltv: f64,
+    pub adjust_time: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flexible_loan() {
+        let mut request =
+            LoanBorrowRequest::init("USDT".to_string(), "BTC".to_string(), BaseRequest::init());
+        assert!(request.validate().is_err());
+        request.loan_amount = Some(1000.0);
+        assert!(request.validate().is_ok());
+        assert!(request
+            .to_string()
+            .starts_with("loanCoin=USDT&loanAmount=1000&collateralCoin=BTC&"));
+
+        let mut history = LoanHistoryRequest::init(BaseRequest::init());
+        history.loan_coin = Some("USDT".to_string());
+        history.limit = Some(20);
+        assert!(RepayHistoryRequest(history)
+            .to_string()
+            .starts_with("loanCoin=USDT&limit=20&"));
+
+        let json = r#"{"loanCoin":"BUSD","collateralCoin":"BNB","remainingDebt":"100.5","remainingCollateral":"5.253","fullRepayment":false,"currentLTV":"0.25","repayStatus":"Repaid"}"#;
+        let repay: LoanRepay = serde_json::from_str(json).unwrap();
+        assert_eq!(repay.repay_status, LoanRepayStatus::Repaid);
+        assert_eq!(repay.current_ltv, 0.25);
+
+        let json = r#"{"rows":[{"loanCoin":"BUSD","initialLoanAmount":"10000","collateralCoin":"BNB","initialCollateralAmount":"49.27565492","borrowTime":1575018510000,"status":"Succeeds"},{"loanCoin":"BUSD","initialLoanAmount":"100","collateralCoin":"BNB","initialCollateralAmount":"1","borrowTime":1575018520000,"status":"Cancelled"}],"total":2}"#;
+        let page: LoanPage<BorrowRecord> = serde_json::from_str(json).unwrap();
+        assert_eq!(page.rows[0].status, LoanStatus::Succeeds);
+        assert_eq!(page.rows[1].status, LoanStatus::Unknown);
+
+        let json = r#"{"rows":[{"loanCoin":"BUSD","collateralCoin":"BNB","direction":"ADDITIONAL","collateralAmount":"5.235","preLTV":"0.78","afterLTV":"0.56","adjustTime":1575018510000}],"total":1}"#;
+        let page: LoanPage<LtvAdjustmentRecord> = serde_json::from_str(json).unwrap();
+        assert_eq!(page.rows[0].direction, LtvDirection::Additional);
+        assert_eq!(page.rows[0].after_ltv, 0.56);
+
+        let json = r#"{"rows":[{"collateralCoin":"BNB","initialLTV":"0.65","marginCallLTV":"0.75","liquidationLTV":"0.83","maxLimit":"1000000"}],"total":1}"#;
+        let page: LoanPage<CollateralAsset> = serde_json::from_str(json).unwrap();
+        assert_eq!(page.rows[0].liquidation_ltv, 0.83);
+    }
+}
@@ -0,0 +1,573 @@
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+use std::future::Future;
+
+use super::{public_enums::OrderSide, BaseRequest, Endpoint, EndpointRequest, SecurityType};
+use crate::client::Client;
+use crate::errors::{BinanceApiError, Result};
+use crate::models::string_or_float;
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::{Deserialize, Serialize, Serializer};
+
+use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, APIEndPoint)]
+pub enum FiatEP {
+    #[endpoint(GET, UserData, url = "/sapi/v1/fiat/orders")]
+    Orders,
+    #[endpoint(GET, UserData, url = "/sapi/v1/fiat/payments")]
+    Payments,
+    #[endpoint(GET, UserData, url = "/sapi/v1/c2c/orderMatch/listUserOrderHistory")]
+    C2cTradeHistory,
+    #[endpoint(GET, UserData, url = "/sapi/v1/pay/transactions")]
+    PayTransactions,
+}
+
+/// Envelope of the fiat, C2C and Pay endpoints, `code` is "000000" on success.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FiatPage<T> {
+    pub code: String,
+    pub message: String,
+    #[serde(default = "Vec::new")]
+    pub data: Vec<T>,
+    pub total: Option<u64>,
+    pub success: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiatTransactionType {
+    Deposit,
+    Withdraw,
+}
+
+impl Serialize for FiatTransactionType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(match self {
+            FiatTransactionType::Deposit => 0,
+            FiatTransactionType::Withdraw => 1,
+        })
+    }
+}
+
+/// `rows` is at most 500.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatOrdersRequest {
+    pub transaction_type: FiatTransactionType,
+    pub begin_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub page: Option<u32>,
+    pub rows: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FiatOrdersRequest {
+    type Response = FiatPage<FiatOrder>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.rows.is_some_and(|r| r > 500) {
+            anyhow::bail!("rows must be at most 500");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatOrder {
+    pub order_no: String,
+    pub fiat_currency: String,
+    #[serde(with = "string_or_float")]
+    pub indicated_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub total_fee: f64,
+    pub method: Option<String>,
+    /// Processing, Failed, Successful, Finished, Refunding, Refunded, ...
+    pub status: String,
+    pub create_time: u64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiatPaymentType {
+    Buy,
+    Sell,
+}
+
+impl Serialize for FiatPaymentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(match self {
+            FiatPaymentType::Buy => 0,
+            FiatPaymentType::Sell => 1,
+        })
+    }
+}
+
+/// Crypto bought or sold with fiat, `rows` is at most 500.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatPaymentsRequest {
+    pub transaction_type: FiatPaymentType,
+    pub begin_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub page: Option<u32>,
+    pub rows: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FiatPaymentsRequest {
+    type Response = FiatPage<FiatPayment>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.rows.is_some_and(|r| r > 500) {
+            anyhow::bail!("rows must be at most 500");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatPayment {
+    pub order_no: String,
+    #[serde(with = "string_or_float")]
+    pub source_amount: f64,
+    pub fiat_currency: String,
+    #[serde(with = "string_or_float")]
+    pub obtain_amount: f64,
+    pub crypto_currency: String,
+    #[serde(with = "string_or_float")]
+    pub total_fee: f64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub status: String,
+    pub payment_method: Option<String>,
+    pub create_time: u64,
+    pub update_time: u64,
+}
+
+/// At most 30 days between the timestamps, `rows` is at most 100.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct C2cTradeHistoryRequest {
+    pub trade_type: Option<OrderSide>,
+    pub start_timestamp: Option<u64>,
+    pub end_timestamp: Option<u64>,
+    pub page: Option<u32>,
+    pub rows: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for C2cTradeHistoryRequest {
+    type Response = FiatPage<C2cTrade>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.rows.is_some_and(|r| r > 100) {
+            anyhow::bail!("rows must be at most 100");
+        }
+        if let (Some(start), Some(end)) = (self.start_timestamp, self.end_timestamp) {
+            if end.saturating_sub(start) > 30 * DAY_MS {
+                anyhow::bail!("time range must be at most 30 days");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct C2cTrade {
+    pub order_number: String,
+    pub adv_no: String,
+    pub trade_type: OrderSide,
+    pub asset: String,
+    pub fiat: String,
+    pub fiat_symbol: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub total_price: f64,
+    #[serde(with = "string_or_float")]
+    pub unit_price: f64,
+    /// PENDING, TRADING, BUYER_PAYED, DISTRIBUTING, COMPLETED, IN_APPEAL,
+    /// CANCELLED, CANCELLED_BY_SYSTEM
+    pub order_status: String,
+    pub create_time: u64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub counter_part_nick_name: String,
+    /// MAKER or TAKER
+    pub advertisement_role: String,
+}
+
+/// At most 90 days between the timestamps, `limit` is at most 100.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct PayTransactionsRequest {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for PayTransactionsRequest {
+    type Response = FiatPage<PayTransaction>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.limit.is_some_and(|l| l > 100) {
+            anyhow::bail!("limit must be at most 100");
+        }
+        if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
+            if end.saturating_sub(start) > 90 * DAY_MS {
+                anyhow::bail!("time range must be at most 90 days");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PayTransaction {
+    /// PAY, PAY_REFUND, C2C, CRYPTO_BOX, PAYOUT, ...
+    pub order_type: String,
+    pub transaction_id: String,
+    pub transaction_time: u64,
+    /// negative when paid out
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub currency: String,
+    /// 1 funding wallet, 2 spot wallet
+    pub wallet_type: Option<u8>,
+    #[serde(default)]
+    pub wallet_types: Vec<u8>,
+    #[serde(default)]
+    pub funds_detail: Vec<PayFundsDetail>,
+    pub payer_info: Option<PayParty>,
+    pub receiver_info: Option<PayParty>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PayFundsDetail {
+    pub currency: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PayParty {
+    pub name: String,
+    /// USER or MERCHANT
+    pub r#type: Option<String>,
+    pub binance_id: Option<u64>,
+    pub account_id: Option<u64>,
+    pub email: Option<String>,
+    pub country_code: Option<u32>,
+    pub phone_number: Option<String>,
+    pub mobile_code: Option<String>,
+    /// institution or card details of payouts
+    pub extend: Option<serde_json::Value>,
+}
+
+fn check_page<T>(page: FiatPage<T>) -> Result<Vec<T>> {
+    if !page.success {
+        return Err(BinanceApiError::Custom(format!(
+            "{}: {}",
+            page.code, page.message
+        )));
+    }
+    Ok(page.data)
+}
+
+/// Walks `[start_time, end_time]` in windows of at most `window` ms and asks
+/// `fetch(begin, end, page)` for the pages of each window until one comes
+/// back with fewer than `rows` records.
+fn paginate<'a, T, F, Fut>(
+    start_time: u64,
+    end_time: u64,
+    window: u64,
+    rows: u32,
+    mut fetch: F,
+) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: 'a,
+    F: FnMut(u64, u64, u32) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + 'a,
+{
+    let first = (start_time <= end_time).then_some((start_time, 1));
+    stream::try_unfold(first, move |state| {
+        let request = state.map(|(begin, page)| {
+            let end = begin.saturating_add(window - 1).min(end_time);
+            (begin, end, page, fetch(begin, end, page))
+        });
+        async move {
+            let Some((begin, end, page, records)) = request else {
+                return Ok::<_, BinanceApiError>(None);
+            };
+            let records = records.await?;
+            let next = if records.len() as u32 >= rows {
+                Some((begin, page + 1))
+            } else if end < end_time {
+                Some((end + 1, 1))
+            } else {
+                None
+            };
+            Ok(Some((records, next)))
+        }
+    })
+    .map_ok(|records| stream::iter(records.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+/// Fiat deposits or withdrawals created between the two timestamps.
+pub fn fiat_orders(
+    client: &Client,
+    transaction_type: FiatTransactionType,
+    start_time: u64,
+    end_time: u64,
+) -> impl Stream<Item = Result<FiatOrder>> + '_ {
+    const ROWS: u32 = 500;
+    paginate(
+        start_time,
+        end_time,
+        90 * DAY_MS,
+        ROWS,
+        move |begin, end, page| {
+            let mut request = FiatOrdersRequest::init(transaction_type, BaseRequest::init());
+            request.begin_time = Some(begin);
+            request.end_time = Some(end);
+            request.page = Some(page);
+            request.rows = Some(ROWS);
+            async move { check_page(client.access(&FiatEP::Orders, Some(request)).await?) }
+        },
+    )
+}
+
+/// Crypto bought or sold with fiat between the two timestamps.
+pub fn fiat_payments(
+    client: &Client,
+    transaction_type: FiatPaymentType,
+    start_time: u64,
+    end_time: u64,
+) -> impl Stream<Item = Result<FiatPayment>> + '_ {
+    const ROWS: u32 = 500;
+    paginate(
+        start_time,
+        end_time,
+        90 * DAY_MS,
+        ROWS,
+        move |begin, end, page| {
+            let mut request = FiatPaymentsRequest::init(transaction_type, BaseRequest::init());
+            request.begin_time = Some(begin);
+            request.end_time = Some(end);
+            request.page = Some(page);
+            request.rows = Some(ROWS);
+            async move { check_page(client.access(&FiatEP::Payments, Some(request)).await?) }
+        },
+    )
+}
+
+/// C2C trades between the two timestamps, both sides unless `trade_type` is set.
+pub fn c2c_trades(
+    client: &Client,
+    trade_type: Option<OrderSide>,
+    start_time: u64,
+    end_time: u64,
+) -> impl Stream<Item = Result<C2cTrade>> + '_ {
+    const ROWS: u32 = 100;
+    paginate(
+        start_time,
+        end_time,
+        30 * DAY_MS,
+        ROWS,
+        move |begin, end, page| {
+            let mut request = C2cTradeHistoryRequest::init(BaseRequest::init());
+            request.trade_type = trade_type.clone();
+            request.start_timestamp = Some(begin);
+            request.end_timestamp = Some(end);
+            request.page = Some(page);
+            request.rows = Some(ROWS);
+            async move {
+                check_page(
+                    client
+                        .access(&FiatEP::C2cTradeHistory, Some(request))
+                        .await?,
+                )
+            }
+        },
+    )
+}
+
+/// Binance Pay transactions between the two timestamps.
+pub fn pay_transactions(
+    client: &Client,
+    start_time: u64,
+    end_time: u64,
+) -> impl Stream<Item = Result<PayTransaction>> + '_ {
+    const LIMIT: u32 = 100;
+    paginate_pay(start_time, end_time, LIMIT, move |begin, end| {
+        let mut request = PayTransactionsRequest::init(BaseRequest::init());
+        request.start_time = Some(begin);
+        request.end_time = Some(end);
+        request.limit = Some(LIMIT);
+        async move {
+            check_page(
+                client
+                    .access(&FiatEP::PayTransactions, Some(request))
+                    .await?,
+            )
+        }
+    })
+}
+
+/// The Pay endpoint has no page parameter and returns the newest records
+/// first, so a full page moves the end of the window back to its oldest
+/// transaction. That millisecond is asked for again and the transactions
+/// already returned are skipped. Only a full page within a single millisecond
+/// moves on to the millisecond before it.
+fn paginate_pay<'a, F, Fut>(
+    start_time: u64,
+    end_time: u64,
+    limit: u32,
+    mut fetch: F,
+) -> impl Stream<Item = Result<PayTransaction>> + 'a
+where
+    F: FnMut(u64, u64) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<PayTransaction>>> + 'a,
+{
+    let first = (start_time <= end_time).then(|| (end_time, HashSet::new()));
+    stream::try_unfold(first, move |state| {
+        let request = state.map(|(end, seen)| {
+            let begin = end.saturating_sub(90 * DAY_MS - 1).max(start_time);
+            (begin, end, seen, fetch(begin, end))
+        });
+        async move {
+            let Some((begin, end, seen, records)) = request else {
+                return Ok::<_, BinanceApiError>(None);
+            };
+            let records = records.await?;
+            let full = records.len() as u32 >= limit;
+            let next = match records.iter().map(|t| t.transaction_time).min() {
+                Some(oldest) if full && oldest < end => {
+                    let seen = records
+                        .iter()
+                        .filter(|t| t.transaction_time == oldest)
+                        .map(|t| t.transaction_id.clone())
+                        .collect();
+                    Some((oldest, seen))
+                }
+                Some(_) if full && end > begin => Some((end - 1, HashSet::new())),
+                _ => (begin > start_time).then(|| (begin - 1, HashSet::new())),
+            };
+            let records: Vec<_> = records
+                .into_iter()
+                .filter(|t| !seen.contains(&t.transaction_id))
+                .collect();
+            Ok(Some((records, next)))
+        }
+    })
+    .map_ok(|records| stream::iter(records.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginate() {
+        let calls = std::cell::RefCell::new(Vec::new());
+        let records: Vec<u64> = futures::executor::block_on(
+            paginate(0, 25 * DAY_MS, 10 * DAY_MS, 2, |begin, end, page| {
+                calls.borrow_mut().push((begin, end, page));
+                // two full pages in the first window, one record elsewhere
+                let records = match (begin, page) {
+                    (0, 1 | 2) => vec![begin, begin],
+                    _ => vec![begin],
+                };
+                async move { Ok(records) }
+            })
+            .try_collect(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                (0, 10 * DAY_MS - 1, 1),
+                (0, 10 * DAY_MS - 1, 2),
+                (0, 10 * DAY_MS - 1, 3),
+                (10 * DAY_MS, 20 * DAY_MS - 1, 1),
+                (20 * DAY_MS, 25 * DAY_MS, 1),
+            ]
+        );
+        assert_eq!(records.len(), 7);
+
+        // the first page ends inside the millisecond shared by a, b and c
+        let pay = |id: &str, transaction_time: u64| PayTransaction {
+            order_type: "PAY".to_string(),
+            transaction_id: id.to_string(),
+            transaction_time,
+            amount: 1.0,
+            currency: "USDT".to_string(),
+            wallet_type: None,
+            wallet_types: vec![],
+            funds_detail: vec![],
+            payer_info: None,
+            receiver_info: None,
+        };
+        let all = [
+            pay("e", 7),
+            pay("a", 5),
+            pay("b", 5),
+            pay("c", 5),
+            pay("d", 3),
+        ];
+        let calls = std::cell::RefCell::new(Vec::new());
+        let records: Vec<PayTransaction> = futures::executor::block_on(
+            paginate_pay(0, 10, 3, |begin, end| {
+                calls.borrow_mut().push((begin, end));
+                let page = all
+                    .iter()
+                    .filter(|t| (begin..=end).contains(&t.transaction_time))
+                    .take(3)
+                    .cloned()
+                    .collect();
+                async move { Ok(page) }
+            })
+            .try_collect(),
+        )
+        .unwrap();
+        assert_eq!(*calls.borrow(), vec![(0, 10), (0, 5), (0, 4)]);
+        let ids: Vec<_> = records.iter().map(|t| t.transaction_id.as_str()).collect();
+        assert_eq!(ids, ["e", "a", "b", "c", "d"]);
+
+        let request = FiatOrdersRequest::init(FiatTransactionType::Withdraw, BaseRequest::init());
+        assert!(request.to_string().starts_with("transactionType=1&"));
+
+        let json = r#"{"code":"000000","message":"success","data":[{"orderNo":"353fca443f06466db0c4dc89f94f027a","sourceAmount":"20.0","fiatCurrency":"EUR","obtainAmount":"4.462","cryptoCurrency":"LUNA","totalFee":"0.2","price":"4.437472","status":"Failed","paymentMethod":"Credit Card","createTime":1624529919000,"updateTime":1624529919000}],"total":1,"success":true}"#;
+        let page: FiatPage<FiatPayment> = serde_json::from_str(json).unwrap();
+        assert_eq!(check_page(page).unwrap()[0].crypto_currency, "LUNA");
+
+        let json = r#"{"code":"000000","message":"success","data":[{"orderType":"C2C","transactionId":"M_P_71505104267788288","transactionTime":1610090460133,"amount":"23.72469206","currency":"BNB","walletType":1,"walletTypes":[1,2],"fundsDetail":[{"currency":"USDT","amount":"1.2"}],"payerInfo":{"name":"Jack","type":"USER","binanceId":12345678,"accountId":67736251},"receiverInfo":{"name":"Alan","type":"MERCHANT","email":"alan@binance.com","binanceId":34355667,"accountId":21326891,"countryCode":1,"phoneNumber":"8057651210","mobileCode":"US","extend":{"institutionName":"","cardNumber":"","digitalWalletId":""}}}],"success":true}"#;
+        let page: FiatPage<PayTransaction> = serde_json::from_str(json).unwrap();
+        let transaction = &check_page(page).unwrap()[0];
+        assert_eq!(transaction.funds_detail[0].amount, 1.2);
+        assert_eq!(
+            transaction.receiver_info.as_ref().unwrap().country_code,
+            Some(1)
+        );
+    }
+}
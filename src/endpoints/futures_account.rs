@@ -1,6 +1,14 @@
+use std::{collections::HashMap, time::Duration};
+
 use crate::{
-    endpoints::{public_enums::*, BaseRequest, Endpoint, EndpointRequest, SecurityType},
-    models::*,
+    client::Client,
+    endpoints::{
+        futures_trading::{FutureOrderType, MarginType, PositionSide, TimeInForce, UserTrade},
+        public_enums::OrderSide,
+        BaseRequest, Endpoint, EndpointRequest, OneOrMany, SecurityType,
+    },
+    errors::{BinanceApiError, Result},
+    models::string_or_bool,
 };
 
 use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
@@ -10,20 +18,99 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, APIEndPoint)]
 #[allow(dead_code)]
 pub enum AccountInfoEP {
-    // #[endpoint(GET, UserData, url = "/fapi/v3/account")]
-    // AccountInfoV3,
+    #[endpoint(GET, UserData, url = "/fapi/v3/account")]
+    AccountInfoV3,
     #[endpoint(GET, UserData, url = "/fapi/v3/balance")]
     Balance,
     #[endpoint(GET, UserData, url = "/fapi/v1/income")]
     IncomeHistory,
+    #[endpoint(GET, UserData, url = "/fapi/v1/commissionRate")]
+    CommissionRate,
+    #[endpoint(GET, UserData, url = "/fapi/v1/leverageBracket")]
+    LeverageBracket,
+    #[endpoint(GET, UserData, url = "/fapi/v1/adlQuantile")]
+    AdlQuantile,
+    #[endpoint(GET, UserData, url = "/fapi/v1/forceOrders")]
+    ForceOrders,
+    #[endpoint(GET, UserData, url = "/fapi/v1/apiTradingStatus")]
+    ApiTradingStatus,
+    #[endpoint(GET, UserData, url = "/fapi/v1/symbolConfig")]
+    SymbolConfig,
+    #[endpoint(GET, UserData, url = "/fapi/v1/income/asyn")]
+    IncomeDownloadId,
+    #[endpoint(GET, UserData, url = "/fapi/v1/income/asyn/id")]
+    IncomeDownloadLink,
+    #[endpoint(GET, UserData, url = "/fapi/v1/order/asyn")]
+    OrderDownloadId,
+    #[endpoint(GET, UserData, url = "/fapi/v1/order/asyn/id")]
+    OrderDownloadLink,
+    #[endpoint(GET, UserData, url = "/fapi/v1/trade/asyn")]
+    TradeDownloadId,
+    #[endpoint(GET, UserData, url = "/fapi/v1/trade/asyn/id")]
+    TradeDownloadLink,
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfoV3Request(pub BaseRequest);
+
+impl EndpointRequest for AccountInfoV3Request {
+    type Response = AccountInfoV3;
+}
+
+/// Only the symbols with positions or open orders are listed in `positions`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfoV3 {
+    pub total_initial_margin: String,
+    pub total_maint_margin: String,
+    pub total_wallet_balance: String,
+    pub total_unrealized_profit: String,
+    pub total_margin_balance: String,
+    pub total_position_initial_margin: String,
+    pub total_open_order_initial_margin: String,
+    pub total_cross_wallet_balance: String,
+    pub total_cross_un_pnl: String,
+    pub available_balance: String,
+    pub max_withdraw_amount: String,
+    pub assets: Vec<AccountAsset>,
+    pub positions: Vec<AccountPosition>,
 }
 
-// #[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
-// #[serde(rename_all = "camelCase")]
-// pub struct AccountInfoV3Request(BaseRequest);
-// impl EndpointRequest for AccountInfoV3Request {
-//     type Response =
-// }
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    pub wallet_balance: String,
+    pub unrealized_profit: String,
+    pub margin_balance: String,
+    pub maint_margin: String,
+    pub initial_margin: String,
+    pub position_initial_margin: String,
+    pub open_order_initial_margin: String,
+    pub cross_wallet_balance: String,
+    pub cross_un_pnl: String,
+    pub available_balance: String,
+    pub max_withdraw_amount: String,
+    /// only returned in Multi-Assets mode
+    pub margin_available: Option<bool>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    pub symbol: String,
+    pub position_side: PositionSide,
+    pub position_amt: String,
+    pub unrealized_profit: String,
+    pub isolated_margin: String,
+    pub notional: String,
+    pub isolated_wallet: String,
+    pub initial_margin: String,
+    pub maint_margin: String,
+    pub update_time: u64,
+}
 
 #[derive(Debug, Serialize, APIRequestToString)]
 #[serde(rename_all = "camelCase")]
@@ -125,3 +212,600 @@ pub struct IncomeHistory {
 impl EndpointRequest for IncomeHistoryRequest {
     type Response = Vec<IncomeHistory>;
 }
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRateRequest {
+    pub symbol: String,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRate {
+    pub symbol: String,
+    pub maker_commission_rate: String,
+    pub taker_commission_rate: String,
+}
+
+impl EndpointRequest for CommissionRateRequest {
+    type Response = CommissionRate;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracketRequest {
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolLeverageBrackets {
+    pub symbol: String,
+    /// only returned when the user's brackets are overwritten
+    pub notional_coef: Option<f64>,
+    pub brackets: Vec<LeverageBracket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracket {
+    pub bracket: u32,
+    /// max initial leverage of this bracket
+    pub initial_leverage: u32,
+    pub notional_cap: f64,
+    pub notional_floor: f64,
+    pub maint_margin_ratio: f64,
+    /// auxiliary number for quick maintenance margin calculation
+    pub cum: f64,
+}
+
+impl EndpointRequest for LeverageBracketRequest {
+    type Response = OneOrMany<SymbolLeverageBrackets>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantileRequest {
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantile {
+    pub symbol: String,
+    pub adl_quantile: AdlQuantileValues,
+}
+
+/// Quantiles from 0 to 4, the less the safer. One-way mode only reports `both`,
+/// hedge mode reports `long`, `short` and `hedge`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct AdlQuantileValues {
+    pub long: Option<u8>,
+    pub short: Option<u8>,
+    pub hedge: Option<u8>,
+    pub both: Option<u8>,
+}
+
+impl EndpointRequest for AdlQuantileRequest {
+    type Response = Vec<AdlQuantile>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AutoCloseType {
+    Liquidation,
+    Adl,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceOrdersRequest {
+    pub symbol: Option<String>,
+    pub auto_close_type: Option<AutoCloseType>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceOrder {
+    pub order_id: u64,
+    pub symbol: String,
+    pub status: String,
+    pub client_order_id: String,
+    pub price: String,
+    pub avg_price: String,
+    pub orig_qty: String,
+    pub executed_qty: String,
+    pub cum_quote: String,
+    pub time_in_force: TimeInForce,
+    pub r#type: FutureOrderType,
+    pub reduce_only: bool,
+    pub close_position: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub stop_price: String,
+    pub working_type: String,
+    pub orig_type: FutureOrderType,
+    pub time: u64,
+    pub update_time: u64,
+}
+
+impl EndpointRequest for ForceOrdersRequest {
+    type Response = Vec<ForceOrder>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if let (Some(start_time), Some(end_time)) = (self.start_time, self.end_time) {
+            if end_time < start_time {
+                anyhow::bail!("start_time must be before end_time")
+            }
+            if end_time - start_time > 86400000 * 7 {
+                anyhow::bail!(
+                    "The time between startTime and endTime cannot be longer than 7 days."
+                )
+            }
+        }
+        if self.limit.is_some_and(|limit| limit > 100) {
+            anyhow::bail!("limit max 100")
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatusRequest {
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatus {
+    /// indicators keyed by symbol, only the triggered ones are listed
+    pub indicators: HashMap<String, Vec<TradingIndicator>>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingIndicator {
+    pub is_locked: bool,
+    /// only meaningful when the symbol is locked
+    pub planned_recover_time: u64,
+    /// "UFR", "IFER", "GCR" or "DR"
+    pub indicator: String,
+    pub value: f64,
+    pub trigger_value: f64,
+}
+
+impl EndpointRequest for ApiTradingStatusRequest {
+    type Response = ApiTradingStatus;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolConfigRequest {
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolConfig {
+    pub symbol: String,
+    pub margin_type: MarginType,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub leverage: u32,
+    pub max_notional_value: String,
+}
+
+impl EndpointRequest for SymbolConfigRequest {
+    type Response = Vec<SymbolConfig>;
+}
+
+/// Shared by the income, order and trade history downloads, the range can
+/// not exceed one year.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadIdRequest {
+    pub start_time: u64,
+    pub end_time: u64,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadId {
+    /// average time taken by the downloads of the last 30 days, in ms
+    pub avg_cost_timestamp_of_last30d: u64,
+    pub download_id: String,
+}
+
+impl EndpointRequest for DownloadIdRequest {
+    type Response = DownloadId;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.end_time < self.start_time {
+            anyhow::bail!("start_time must be before end_time")
+        }
+        if self.end_time - self.start_time > 86400000 * 365 {
+            anyhow::bail!("The time between startTime and endTime cannot be longer than 1 year.")
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadLinkRequest {
+    pub download_id: String,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadStatus {
+    Completed,
+    Processing,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadLink {
+    pub download_id: String,
+    pub status: DownloadStatus,
+    /// empty while processing
+    pub url: String,
+    pub notified: bool,
+    /// -1 while processing
+    pub expiration_timestamp: i64,
+    pub is_expired: Option<bool>,
+}
+
+impl EndpointRequest for DownloadLinkRequest {
+    type Response = DownloadLink;
+}
+
+/// Requests a download ID from `id_endpoint` and polls `link_endpoint` every
+/// `poll_interval` until the file is ready, giving up after `max_wait`.
+pub async fn wait_for_download_link(
+    client: &Client,
+    id_endpoint: &AccountInfoEP,
+    link_endpoint: &AccountInfoEP,
+    start_time: u64,
+    end_time: u64,
+    poll_interval: Duration,
+    max_wait: Duration,
+) -> Result<String> {
+    let req = DownloadIdRequest::init(start_time, end_time, BaseRequest::init());
+    req.validate()
+        .map_err(|e| BinanceApiError::Custom(e.to_string()))?;
+    let download_id = client
+        .access::<DownloadIdRequest>(id_endpoint, Some(req))
+        .await?
+        .download_id;
+
+    let started = std::time::Instant::now();
+    loop {
+        let link = client
+            .access::<DownloadLinkRequest>(
+                link_endpoint,
+                Some(DownloadLinkRequest::init(
+                    download_id.clone(),
+                    BaseRequest::init(),
+                )),
+            )
+            .await?;
+        if link.status == DownloadStatus::Completed {
+            return Ok(link.url);
+        }
+        if started.elapsed() + poll_interval > max_wait {
+            return Err(BinanceApiError::Custom(format!(
+                "download {download_id} not ready after {max_wait:?}"
+            )));
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+async fn download_csv(
+    client: &Client,
+    id_endpoint: &AccountInfoEP,
+    link_endpoint: &AccountInfoEP,
+    start_time: u64,
+    end_time: u64,
+    poll_interval: Duration,
+    max_wait: Duration,
+) -> Result<String> {
+    let url = wait_for_download_link(
+        client,
+        id_endpoint,
+        link_endpoint,
+        start_time,
+        end_time,
+        poll_interval,
+        max_wait,
+    )
+    .await?;
+    client.get_text(&url).await
+}
+
+/// Downloads the income history between `start_time` and `end_time`, which,
+/// unlike `IncomeHistoryRequest`, is not limited to the last 3 months.
+pub async fn download_income_history(
+    client: &Client,
+    start_time: u64,
+    end_time: u64,
+    poll_interval: Duration,
+    max_wait: Duration,
+) -> Result<Vec<IncomeHistory>> {
+    let csv = download_csv(
+        client,
+        &AccountInfoEP::IncomeDownloadId,
+        &AccountInfoEP::IncomeDownloadLink,
+        start_time,
+        end_time,
+        poll_interval,
+        max_wait,
+    )
+    .await?;
+    parse_income_history_csv(&csv)
+}
+
+/// Downloads the trade history between `start_time` and `end_time`.
+pub async fn download_trade_history(
+    client: &Client,
+    start_time: u64,
+    end_time: u64,
+    poll_interval: Duration,
+    max_wait: Duration,
+) -> Result<Vec<UserTrade>> {
+    let csv = download_csv(
+        client,
+        &AccountInfoEP::TradeDownloadId,
+        &AccountInfoEP::TradeDownloadLink,
+        start_time,
+        end_time,
+        poll_interval,
+        max_wait,
+    )
+    .await?;
+    parse_trade_history_csv(&csv)
+}
+
+/// Columns of the income history export.
+const INCOME_HEADERS: &[&str] = &[
+    "Uid",
+    "Time",
+    "Symbol",
+    "Income Type",
+    "Amount",
+    "Asset",
+    "Info",
+    "Transaction ID",
+    "Trade ID",
+];
+
+/// Columns of the trade history export.
+const TRADE_HEADERS: &[&str] = &[
+    "Uid",
+    "Time",
+    "Symbol",
+    "Trade ID",
+    "Order ID",
+    "Side",
+    "Position Side",
+    "Price",
+    "Quantity",
+    "Amount",
+    "Fee",
+    "Fee Asset",
+    "Realized Profit",
+    "Buyer",
+    "Maker",
+];
+
+/// Export times are UTC, "2024-03-01 08:00:00".
+fn deserialize_csv_time<'de, D>(deserializer: D) -> std::result::Result<i64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let time = String::deserialize(deserializer)?;
+    chrono::NaiveDateTime::parse_from_str(&time, "%Y-%m-%d %H:%M:%S")
+        .map(|t| t.and_utc().timestamp_millis())
+        .map_err(|e| serde::de::Error::custom(format!("invalid time {time}: {e}")))
+}
+
+#[derive(Deserialize)]
+struct IncomeHistoryRow {
+    #[serde(rename = "Time", deserialize_with = "deserialize_csv_time")]
+    time: i64,
+    #[serde(rename = "Symbol")]
+    symbol: Option<String>,
+    #[serde(rename = "Income Type")]
+    income_type: IncomeType,
+    #[serde(rename = "Amount")]
+    amount: String,
+    #[serde(rename = "Asset")]
+    asset: String,
+    #[serde(rename = "Info")]
+    info: String,
+    #[serde(rename = "Transaction ID")]
+    tran_id: u64,
+    #[serde(rename = "Trade ID")]
+    trade_id: Option<String>,
+}
+
+impl From<IncomeHistoryRow> for IncomeHistory {
+    fn from(row: IncomeHistoryRow) -> Self {
+        IncomeHistory {
+            symbol: row.symbol,
+            income_type: row.income_type,
+            income: row.amount,
+            asset: row.asset,
+            info: row.info,
+            time: row.time,
+            tran_id: row.tran_id,
+            trade_id: row.trade_id,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TradeHistoryRow {
+    #[serde(rename = "Time", deserialize_with = "deserialize_csv_time")]
+    time: i64,
+    #[serde(rename = "Symbol")]
+    symbol: String,
+    #[serde(rename = "Trade ID")]
+    id: u64,
+    #[serde(rename = "Order ID")]
+    order_id: u64,
+    #[serde(rename = "Side")]
+    side: OrderSide,
+    #[serde(rename = "Position Side")]
+    position_side: PositionSide,
+    #[serde(rename = "Price")]
+    price: String,
+    #[serde(rename = "Quantity")]
+    qty: String,
+    #[serde(rename = "Amount")]
+    quote_qty: String,
+    #[serde(rename = "Fee")]
+    commission: String,
+    #[serde(rename = "Fee Asset")]
+    commission_asset: String,
+    #[serde(rename = "Realized Profit")]
+    realized_pnl: String,
+    #[serde(rename = "Buyer")]
+    buyer: bool,
+    #[serde(rename = "Maker")]
+    maker: bool,
+}
+
+impl From<TradeHistoryRow> for UserTrade {
+    fn from(row: TradeHistoryRow) -> Self {
+        UserTrade {
+            buyer: row.buyer,
+            commission: row.commission,
+            commission_asset: row.commission_asset,
+            id: row.id,
+            maker: row.maker,
+            order_id: row.order_id,
+            price: row.price,
+            qty: row.qty,
+            quote_qty: row.quote_qty,
+            realized_pnl: row.realized_pnl,
+            side: row.side,
+            position_side: row.position_side,
+            symbol: row.symbol,
+            time: row.time,
+        }
+    }
+}
+
+/// Fails if any of `headers` is missing, then converts every row.
+fn parse_csv<R, T>(csv: &str, headers: &[&str]) -> Result<Vec<T>>
+where
+    R: for<'de> Deserialize<'de>,
+    T: From<R>,
+{
+    let csv_error = |e: csv::Error| BinanceApiError::Custom(format!("CSV: {e}"));
+    let mut reader = csv::Reader::from_reader(csv.as_bytes());
+    let found = reader.headers().map_err(csv_error)?;
+    let missing = headers
+        .iter()
+        .filter(|h| !found.iter().any(|f| f == **h))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(BinanceApiError::Custom(format!(
+            "CSV is missing columns {missing:?}"
+        )));
+    }
+    reader
+        .deserialize::<R>()
+        .map(|row| row.map(T::from).map_err(csv_error))
+        .collect()
+}
+
+/// Parses a downloaded income history CSV.
+pub fn parse_income_history_csv(csv: &str) -> Result<Vec<IncomeHistory>> {
+    parse_csv::<IncomeHistoryRow, _>(csv, INCOME_HEADERS)
+}
+
+/// Parses a downloaded trade history CSV.
+pub fn parse_trade_history_csv(csv: &str) -> Result<Vec<UserTrade>> {
+    parse_csv::<TradeHistoryRow, _>(csv, TRADE_HEADERS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_risk_parameters() {
+        let json = r#"[
+            {"symbol":"ETHUSDT","adlQuantile":{"LONG":3,"SHORT":3,"HEDGE":0}},
+            {"symbol":"BTCUSDT","adlQuantile":{"LONG":1,"SHORT":2,"BOTH":0}}
+        ]"#;
+        let adl: Vec<AdlQuantile> = serde_json::from_str(json).unwrap();
+        assert_eq!(adl[0].adl_quantile.hedge, Some(0));
+        assert_eq!(adl[1].adl_quantile.both, Some(0));
+
+        let json = r#"[{"symbol":"BTCUSDT","marginType":"CROSSED","isAutoAddMargin":"false","leverage":21,"maxNotionalValue":"1000000"}]"#;
+        let config: Vec<SymbolConfig> = serde_json::from_str(json).unwrap();
+        assert_eq!(config[0].margin_type, MarginType::Crossed);
+        assert!(!config[0].is_auto_add_margin);
+
+        let json = r#"{"indicators":{"BTCUSDT":[{"isLocked":true,"plannedRecoverTime":1545741270000,"indicator":"UFR","value":0.05,"triggerValue":0.995}]},"updateTime":1545741270000}"#;
+        let status: ApiTradingStatus = serde_json::from_str(json).unwrap();
+        assert!(status.indicators["BTCUSDT"][0].is_locked);
+    }
+
+    #[test]
+    fn test_download_csv() {
+        let csv = include_str!("../../testdata/futures_income_history.csv");
+        let income = parse_income_history_csv(csv).unwrap();
+        assert_eq!(income.len(), 3);
+        assert_eq!(income[0].time, 1709280000000);
+        assert_eq!(income[0].symbol, None);
+        assert_eq!(income[0].tran_id, 9689322391);
+        assert_eq!(income[1].income, "-0.01230000");
+        assert_eq!(income[1].trade_id, None);
+        assert_eq!(income[2].trade_id.as_deref(), Some("1001"));
+
+        let csv = include_str!("../../testdata/futures_trade_history.csv");
+        let trades = parse_trade_history_csv(csv).unwrap();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].id, 1001);
+        assert_eq!(trades[0].quote_qty, "620.001");
+        assert!(trades[0].maker);
+        assert!(!trades[0].buyer);
+        assert!(trades[1].buyer);
+
+        // no made-up flags when a column is missing
+        let csv = TRADE_HEADERS[..TRADE_HEADERS.len() - 1].join(",")
+            + "\n1,2024-03-01 08:00:00,BTCUSDT,1001,2002,SELL,BOTH,62000.1,0.010,620.001,0.248,USDT,1.2,false\n";
+        assert!(parse_trade_history_csv(&csv)
+            .unwrap_err()
+            .to_string()
+            .contains("Maker"));
+        assert!(parse_income_history_csv("Time,Amount\n").is_err());
+    }
+}
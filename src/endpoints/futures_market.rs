@@ -1,140 +1,699 @@
 use crate::{
-    endpoints::{public_enums::*, BaseRequest, Endpoint, EndpointRequest, SecurityType},
-    models::*,
+    endpoints::{
+        futures_trading::{FutureOrderType, TimeInForce},
+        spot_market::KlineInterval,
+        usd_m_futures::{ContractStatus, ContractType},
+        Endpoint, EndpointRequest, OneOrMany, SecurityType,
+    },
+    models::{AggTrade, Asks, Bids, KlineSummary, MarkPriceEvent, RateLimit},
 };
 
 use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
 
 use serde::{Deserialize, Serialize};
-use strum::Display;
 
 #[derive(Debug, APIEndPoint)]
 #[allow(dead_code)]
 pub enum FuturesMarketEP {
     #[endpoint(GET, None, url = "/fapi/v1/exchangeInfo")]
     ExchangeInfo,
+    #[endpoint(GET, None, url = "/fapi/v1/depth")]
+    OrderBook,
+    #[endpoint(GET, None, url = "/fapi/v1/trades")]
+    RecentTrades,
+    #[endpoint(GET, MarketData, url = "/fapi/v1/historicalTrades")]
+    HistoricalTrades,
+    #[endpoint(GET, None, url = "/fapi/v1/aggTrades")]
+    AggTrades,
+    #[endpoint(GET, None, url = "/fapi/v1/klines")]
+    Klines,
+    #[endpoint(GET, None, url = "/fapi/v1/continuousKlines")]
+    ContinuousKlines,
+    #[endpoint(GET, None, url = "/fapi/v1/indexPriceKlines")]
+    IndexPriceKlines,
+    #[endpoint(GET, None, url = "/fapi/v1/markPriceKlines")]
+    MarkPriceKlines,
+    #[endpoint(GET, None, url = "/fapi/v1/premiumIndex")]
+    PremiumIndex,
+    #[endpoint(GET, None, url = "/fapi/v1/fundingRate")]
+    FundingRateHistory,
+    #[endpoint(GET, None, url = "/fapi/v1/fundingInfo")]
+    FundingInfo,
+    #[endpoint(GET, None, url = "/fapi/v1/ticker/24hr")]
+    Ticker24hr,
+    #[endpoint(GET, None, url = "/fapi/v1/openInterest")]
+    OpenInterest,
+    #[endpoint(GET, None, url = "/futures/data/openInterestHist")]
+    OpenInterestHist,
+    #[endpoint(GET, None, url = "/futures/data/topLongShortAccountRatio")]
+    TopLongShortAccountRatio,
+    #[endpoint(GET, None, url = "/futures/data/topLongShortPositionRatio")]
+    TopLongShortPositionRatio,
+    #[endpoint(GET, None, url = "/futures/data/globalLongShortAccountRatio")]
+    GlobalLongShortAccountRatio,
+    #[endpoint(GET, None, url = "/futures/data/takerlongshortRatio")]
+    TakerLongShortRatio,
+    #[endpoint(GET, None, url = "/futures/data/basis")]
+    Basis,
 }
 
 #[derive(Debug, APIRequestInit, APIRequestToString, Serialize, Deserialize)]
 pub struct ExchangeInfoRequest {}
 
 impl EndpointRequest for ExchangeInfoRequest {
-    type Response = serde_json::Value;
-}
-
-/*
- * {
-    "exchangeFilters": [],
-     "rateLimits": [ // API访问的限制
-         {
-             "interval": "MINUTE", // 按照分钟计算
-               "intervalNum": 1, // 按照1分钟计算
-               "limit": 2400, // 上限次数
-               "rateLimitType": "REQUEST_WEIGHT" // 按照访问权重来计算
-           },
-          {
-              "interval": "MINUTE",
-               "intervalNum": 1,
-               "limit": 1200,
-               "rateLimitType": "ORDERS" // 按照订单数量来计算
-           }
-       ],
-     "serverTime": 1565613908500, // 请忽略。如果需要获取当前系统时间，请查询接口 “GET /fapi/v1/time”
-     "assets": [ // 资产信息
-         {
-             "asset": "BTC",
-               "marginAvailable": true, // 是否可用作保证金
-               "autoAssetExchange": "-0.10" // 保证金资产自动兑换阈值
-           },
-         {
-             "asset": "USDT",
-               "marginAvailable": true, // 是否可用作保证金
-               "autoAssetExchange": "0" // 保证金资产自动兑换阈值
-           },
-         {
-             "asset": "BNB",
-               "marginAvailable": false, // 是否可用作保证金
-               "autoAssetExchange": null // 保证金资产自动兑换阈值
-           }
-       ],
-     "symbols": [ // 交易对信息
-         {
-             "symbol": "BLZUSDT",  // 交易对
-             "pair": "BLZUSDT",  // 标的交易对
-             "contractType": "PERPETUAL",	// 合约类型
-             "deliveryDate": 4133404800000,  // 交割日期
-             "onboardDate": 1598252400000,	  // 上线日期
-             "status": "TRADING",  // 交易对状态
-             "maintMarginPercent": "2.5000",  // 请忽略
-             "requiredMarginPercent": "5.0000", // 请忽略
-             "baseAsset": "BLZ",  // 标的资产
-             "quoteAsset": "USDT", // 报价资产
-             "marginAsset": "USDT", // 保证金资产
-             "pricePrecision": 5,  // 价格小数点位数(仅作为系统精度使用，注意同tickSize 区分）
-             "quantityPrecision": 0,  // 数量小数点位数(仅作为系统精度使用，注意同stepSize 区分）
-             "baseAssetPrecision": 8,  // 标的资产精度
-             "quotePrecision": 8,  // 报价资产精度
-             "underlyingType": "COIN",
-             "underlyingSubType": ["STORAGE"],
-             "settlePlan": 0,
-             "triggerProtect": "0.15", // 开启"priceProtect"的条件订单的触发阈值
-             "filters": [
-                 {
-                     "filterType": "PRICE_FILTER", // 价格限制
-                     "maxPrice": "300", // 价格上限, 最大价格
-                     "minPrice": "0.0001", // 价格下限, 最小价格
-                     "tickSize": "0.0001" // 订单最小价格间隔
-                 },
+    type Response = FuturesExchangeInformation;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub assets: Vec<FuturesAsset>,
+    pub symbols: Vec<FuturesSymbol>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAsset {
+    pub asset: String,
+    /// whether the asset can be used as margin in Multi-Assets mode
+    pub margin_available: bool,
+    /// auto-exchange threshold in Multi-Assets margin mode
+    pub auto_asset_exchange: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSymbol {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub status: ContractStatus,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub margin_asset: String,
+    /// system precision only, use the tick size of the price filter for orders
+    pub price_precision: u32,
+    /// system precision only, use the step size of the lot size filter for orders
+    pub quantity_precision: u32,
+    pub base_asset_precision: u32,
+    pub quote_precision: u32,
+    pub underlying_type: String,
+    #[serde(default)]
+    pub underlying_sub_type: Vec<String>,
+    /// threshold for algo orders with "priceProtect"
+    pub trigger_protect: String,
+    pub liquidation_fee: String,
+    /// the max price difference rate (from mark price) a market order can make
+    pub market_take_bound: String,
+    pub filters: Vec<FuturesFilters>,
+    #[serde(alias = "OrderType")]
+    pub order_types: Vec<FutureOrderType>,
+    pub time_in_force: Vec<TimeInForce>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "filterType")]
+pub enum FuturesFilters {
+    #[serde(rename = "PRICE_FILTER")]
+    #[serde(rename_all = "camelCase")]
+    PriceFilter {
+        min_price: String,
+        max_price: String,
+        tick_size: String,
+    },
+    #[serde(rename = "LOT_SIZE")]
+    #[serde(rename_all = "camelCase")]
+    LotSize {
+        min_qty: String,
+        max_qty: String,
+        step_size: String,
+    },
+    #[serde(rename = "MARKET_LOT_SIZE")]
+    #[serde(rename_all = "camelCase")]
+    MarketLotSize {
+        min_qty: String,
+        max_qty: String,
+        step_size: String,
+    },
+    #[serde(rename = "MAX_NUM_ORDERS")]
+    MaxNumOrders { limit: u32 },
+    #[serde(rename = "MAX_NUM_ALGO_ORDERS")]
+    MaxNumAlgoOrders { limit: u32 },
+    #[serde(rename = "MIN_NOTIONAL")]
+    MinNotional { notional: String },
+    #[serde(rename = "PERCENT_PRICE")]
+    #[serde(rename_all = "camelCase")]
+    PercentPrice {
+        multiplier_up: String,
+        multiplier_down: String,
+        multiplier_decimal: String,
+    },
+    /// filters added after this enum was written
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBookRequest {
+    pub symbol: String,
+    /// 5, 10, 20, 50, 100, 500, 1000
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for OrderBookRequest {
+    type Response = FuturesOrderBook;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesOrderBook {
+    pub last_update_id: u64,
+    #[serde(rename = "E")]
+    pub message_output_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentTradesRequest {
+    pub symbol: String,
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for RecentTradesRequest {
+    type Response = Vec<FuturesTrade>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalTradesRequest {
+    pub symbol: String,
+    pub limit: Option<u16>,
+    pub from_id: Option<u64>,
+}
+impl EndpointRequest for HistoricalTradesRequest {
+    type Response = Vec<FuturesTrade>;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesTrade {
+    pub id: u64,
+    pub price: String,
+    pub qty: String,
+    pub quote_qty: String,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AggTradesRequest {
+    pub symbol: String,
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for AggTradesRequest {
+    type Response = Vec<AggTrade>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinesRequest {
+    pub symbol: String,
+    pub interval: KlineInterval,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for KlinesRequest {
+    type Response = Vec<KlineSummary>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_kline_params(self.interval, self.limit)
+    }
+}
+
+/// Klines of a continuous contract, the rows have the same layout as the
+/// regular klines.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinuousKlinesRequest {
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub interval: KlineInterval,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for ContinuousKlinesRequest {
+    type Response = Vec<KlineSummary>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_kline_params(self.interval, self.limit)
+    }
+}
+
+/// Index price klines, all the volume fields of the rows are zero and
+/// `number_of_trades` holds the number of basic data.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPriceKlinesRequest {
+    pub pair: String,
+    pub interval: KlineInterval,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for IndexPriceKlinesRequest {
+    type Response = Vec<KlineSummary>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_kline_params(self.interval, self.limit)
+    }
+}
+
+/// Mark price klines, all the volume fields of the rows are zero and
+/// `number_of_trades` holds the number of basic data.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPriceKlinesRequest {
+    pub symbol: String,
+    pub interval: KlineInterval,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for MarkPriceKlinesRequest {
+    type Response = Vec<KlineSummary>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_kline_params(self.interval, self.limit)
+    }
+}
+
+fn validate_kline_params(interval: KlineInterval, limit: Option<u16>) -> anyhow::Result<()> {
+    if interval == KlineInterval::_1s {
+        anyhow::bail!("1s interval is not supported by futures klines")
+    }
+    if limit.is_some_and(|limit| limit > 1500) {
+        anyhow::bail!("limit max 1500")
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndexRequest {
+    pub symbol: Option<String>,
+}
+impl EndpointRequest for PremiumIndexRequest {
+    type Response = OneOrMany<PremiumIndex>;
+}
+
+/// Mark price and funding rate, the stream event doubles as the REST model.
+pub type PremiumIndex = MarkPriceEvent;
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistoryRequest {
+    pub symbol: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for FundingRateHistoryRequest {
+    type Response = Vec<FundingRate>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.limit.is_some_and(|limit| limit > 1000) {
+            anyhow::bail!("limit max 1000")
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    pub funding_rate: String,
+    pub funding_time: u64,
+    pub mark_price: String,
+}
+
+#[derive(Debug, APIRequestInit, APIRequestToString, Serialize, Deserialize)]
+pub struct FundingInfoRequest {}
+impl EndpointRequest for FundingInfoRequest {
+    type Response = Vec<FundingInfo>;
+}
+
+/// Only symbols with adjusted funding rate cap/floor or funding interval are returned.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingInfo {
+    pub symbol: String,
+    pub adjusted_funding_rate_cap: String,
+    pub adjusted_funding_rate_floor: String,
+    pub funding_interval_hours: u32,
+    pub disclaimer: bool,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker24hrRequest {
+    pub symbol: Option<String>,
+}
+impl EndpointRequest for Ticker24hrRequest {
+    type Response = OneOrMany<FuturesPriceStats>;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesPriceStats {
+    pub symbol: String,
+    pub price_change: String,
+    pub price_change_percent: String,
+    pub weighted_avg_price: String,
+    pub last_price: String,
+    pub last_qty: String,
+    pub open_price: String,
+    pub high_price: String,
+    pub low_price: String,
+    pub volume: String,
+    pub quote_volume: String,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestRequest {
+    pub symbol: String,
+}
+impl EndpointRequest for OpenInterestRequest {
+    type Response = OpenInterest;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    pub open_interest: String,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum StatisticsPeriod {
+    #[serde(rename = "5m")]
+    _5m,
+    #[serde(rename = "15m")]
+    _15m,
+    #[serde(rename = "30m")]
+    _30m,
+    #[serde(rename = "1h")]
+    _1h,
+    #[serde(rename = "2h")]
+    _2h,
+    #[serde(rename = "4h")]
+    _4h,
+    #[serde(rename = "6h")]
+    _6h,
+    #[serde(rename = "12h")]
+    _12h,
+    #[serde(rename = "1d")]
+    _1d,
+}
+
+/// Only the data of the latest 30 days is available.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHistRequest {
+    pub symbol: String,
+    pub period: StatisticsPeriod,
+    pub limit: Option<u16>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+impl EndpointRequest for OpenInterestHistRequest {
+    type Response = Vec<OpenInterestHist>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_statistics_limit(self.limit)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHist {
+    pub symbol: String,
+    pub sum_open_interest: String,
+    pub sum_open_interest_value: String,
+    pub timestamp: u64,
+}
+
+/// Shared by the top trader account/position ratio and the global account ratio.
+///
+/// Only the data of the latest 30 days is available.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatioRequest {
+    pub symbol: String,
+    pub period: StatisticsPeriod,
+    pub limit: Option<u16>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+impl EndpointRequest for LongShortRatioRequest {
+    type Response = Vec<LongShortRatio>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_statistics_limit(self.limit)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub symbol: String,
+    pub long_short_ratio: String,
+    pub long_account: String,
+    pub short_account: String,
+    pub timestamp: u64,
+}
+
+/// Only the data of the latest 30 days is available.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerLongShortRatioRequest {
+    pub symbol: String,
+    pub period: StatisticsPeriod,
+    pub limit: Option<u16>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+impl EndpointRequest for TakerLongShortRatioRequest {
+    type Response = Vec<TakerLongShortRatio>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_statistics_limit(self.limit)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerLongShortRatio {
+    pub buy_sell_ratio: String,
+    pub buy_vol: String,
+    pub sell_vol: String,
+    pub timestamp: u64,
+}
+
+/// Only the data of the latest 30 days is available.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct BasisRequest {
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub period: StatisticsPeriod,
+    pub limit: Option<u16>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+impl EndpointRequest for BasisRequest {
+    type Response = Vec<Basis>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_statistics_limit(self.limit)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Basis {
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub index_price: String,
+    pub futures_price: String,
+    pub basis: String,
+    pub basis_rate: String,
+    pub annualized_basis_rate: String,
+    pub timestamp: u64,
+}
+
+fn validate_statistics_limit(limit: Option<u16>) -> anyhow::Result<()> {
+    if limit.is_some_and(|limit| limit > 500) {
+        anyhow::bail!("limit max 500")
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_market_data() {
+        let json = r#"{"symbol":"BTCUSDT","markPrice":"11793.63104562","indexPrice":"11781.80495970","estimatedSettlePrice":"11781.16138815","lastFundingRate":"0.00038246","interestRate":"0.00010000","nextFundingTime":1597392000000,"time":1597370495002}"#;
+        let index: OneOrMany<PremiumIndex> = serde_json::from_str(json).unwrap();
+        let OneOrMany::One(index) = index else {
+            panic!("expected a single premium index")
+        };
+        assert_eq!(index.funding_rate, "0.00038246");
+        assert_eq!(index.event_time, 1597370495002);
+        assert_eq!(index.interest_rate.as_deref(), Some("0.00010000"));
+        let all: OneOrMany<PremiumIndex> = serde_json::from_str(&format!("[{json}]")).unwrap();
+        assert!(matches!(all, OneOrMany::Many(v) if v[0].symbol == "BTCUSDT"));
+
+        let json = r#"{"e":"markPriceUpdate","E":1562305380000,"s":"BTCUSDT","p":"11794.15000000","i":"11784.62659091","P":"11784.25641265","r":"0.00038167","T":1562306400000}"#;
+        let event: MarkPriceEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event.event_type, "markPriceUpdate");
+        assert_eq!(event.interest_rate, None);
+
+        let json = r#"[{"symbol":"BTCUSDT","fundingRate":"-0.03750000","fundingTime":1570608000000,"markPrice":"34287.54619963"}]"#;
+        let rates: Vec<FundingRate> = serde_json::from_str(json).unwrap();
+        assert_eq!(rates[0].funding_time, 1570608000000);
+
+        let json = r#"[{"symbol":"BTCUSDT","sumOpenInterest":"20403.63700000","sumOpenInterestValue":"150570784.07809979","timestamp":1583127900000}]"#;
+        let hist: Vec<OpenInterestHist> = serde_json::from_str(json).unwrap();
+        assert_eq!(hist[0].sum_open_interest, "20403.63700000");
+
+        let json = r#"[{"symbol":"BTCUSDT","longShortRatio":"1.4342","longAccount":"0.5891","shortAccount":"0.4108","timestamp":1583139600000}]"#;
+        let ratios: Vec<LongShortRatio> = serde_json::from_str(json).unwrap();
+        assert_eq!(ratios[0].long_short_ratio, "1.4342");
+
+        let json = r#"[{"buySellRatio":"1.5586","buyVol":"387.3300","sellVol":"248.5030","timestamp":1585614900000}]"#;
+        let ratios: Vec<TakerLongShortRatio> = serde_json::from_str(json).unwrap();
+        assert_eq!(ratios[0].sell_vol, "248.5030");
+
+        let json = r#"[{"indexPrice":"34400.15945055","contractType":"PERPETUAL","basisRate":"0.0004","futuresPrice":"34414.10","annualizedBasisRate":"","basis":"13.94054945","pair":"BTCUSDT","timestamp":1698742800000}]"#;
+        let basis: Vec<Basis> = serde_json::from_str(json).unwrap();
+        assert_eq!(basis[0].contract_type, ContractType::Perpetual);
+    }
+
+    #[test]
+    fn test_exchange_information() {
+        let json = r#"{
+            "exchangeFilters": [],
+            "rateLimits": [
+                {"interval": "MINUTE", "intervalNum": 1, "limit": 2400, "rateLimitType": "REQUEST_WEIGHT"}
+            ],
+            "serverTime": 1565613908500,
+            "assets": [
+                {"asset": "USDT", "marginAvailable": true, "autoAssetExchange": "0"},
+                {"asset": "BNB", "marginAvailable": false, "autoAssetExchange": null}
+            ],
+            "symbols": [
                 {
-                    "filterType": "LOT_SIZE", // 数量限制
-                     "maxQty": "10000000", // 数量上限, 最大数量
-                     "minQty": "1", // 数量下限, 最小数量
-                     "stepSize": "1" // 订单最小数量间隔
-                 },
+                    "symbol": "BLZUSDT",
+                    "pair": "BLZUSDT",
+                    "contractType": "PERPETUAL",
+                    "deliveryDate": 4133404800000,
+                    "onboardDate": 1598252400000,
+                    "status": "TRADING",
+                    "maintMarginPercent": "2.5000",
+                    "requiredMarginPercent": "5.0000",
+                    "baseAsset": "BLZ",
+                    "quoteAsset": "USDT",
+                    "marginAsset": "USDT",
+                    "pricePrecision": 5,
+                    "quantityPrecision": 0,
+                    "baseAssetPrecision": 8,
+                    "quotePrecision": 8,
+                    "underlyingType": "COIN",
+                    "underlyingSubType": ["STORAGE"],
+                    "settlePlan": 0,
+                    "triggerProtect": "0.15",
+                    "filters": [
+                        {"filterType": "PRICE_FILTER", "maxPrice": "300", "minPrice": "0.0001", "tickSize": "0.0001"},
+                        {"filterType": "LOT_SIZE", "maxQty": "10000000", "minQty": "1", "stepSize": "1"},
+                        {"filterType": "MARKET_LOT_SIZE", "maxQty": "590119", "minQty": "1", "stepSize": "1"},
+                        {"filterType": "MAX_NUM_ORDERS", "limit": 200},
+                        {"filterType": "MAX_NUM_ALGO_ORDERS", "limit": 10},
+                        {"filterType": "MIN_NOTIONAL", "notional": "5.0"},
+                        {"filterType": "PERCENT_PRICE", "multiplierUp": "1.1500", "multiplierDown": "0.8500", "multiplierDecimal": "4"}
+                    ],
+                    "orderTypes": ["LIMIT", "MARKET", "STOP", "STOP_MARKET", "TAKE_PROFIT", "TAKE_PROFIT_MARKET", "TRAILING_STOP_MARKET"],
+                    "timeInForce": ["GTC", "IOC", "FOK", "GTX"],
+                    "liquidationFee": "0.010000",
+                    "marketTakeBound": "0.30"
+                },
                 {
-                    "filterType": "MARKET_LOT_SIZE", // 市价订单数量限制
-                     "maxQty": "590119", // 数量上限, 最大数量
-                     "minQty": "1", // 数量下限, 最小数量
-                     "stepSize": "1" // 允许的步进值
-                 },
-                 {
-                    "filterType": "MAX_NUM_ORDERS", // 最多订单数限制
-                    "limit": 200
-                  },
-                  {
-                    "filterType": "MAX_NUM_ALGO_ORDERS", // 最多条件订单数限制
-                    "limit": 10
-                  },
-                  {
-                      "filterType": "MIN_NOTIONAL",  // 最小名义价值
-                      "notional": "5.0",
-                  },
-                  {
-                    "filterType": "PERCENT_PRICE", // 价格比限制
-                    "multiplierUp": "1.1500", // 价格上限百分比
-                    "multiplierDown": "0.8500", // 价格下限百分比
-                    "multiplierDecimal": "4"
+                    "symbol": "SRMUSDT",
+                    "pair": "SRMUSDT",
+                    "contractType": "",
+                    "deliveryDate": 4133404800000,
+                    "onboardDate": 1598252400000,
+                    "status": "CLOSE",
+                    "baseAsset": "SRM",
+                    "quoteAsset": "USDT",
+                    "marginAsset": "USDT",
+                    "pricePrecision": 4,
+                    "quantityPrecision": 0,
+                    "baseAssetPrecision": 8,
+                    "quotePrecision": 8,
+                    "underlyingType": "COIN",
+                    "triggerProtect": "0.15",
+                    "filters": [
+                        {"filterType": "POSITION_RISK_CONTROL", "positionControlSide": "NONE"}
+                    ],
+                    "OrderType": ["LIMIT"],
+                    "timeInForce": ["GTC"],
+                    "liquidationFee": "0.010000",
+                    "marketTakeBound": "0.30"
                 }
-               ],
-             "OrderType": [ // 订单类型
-                   "LIMIT",  // 限价单
-                   "MARKET",  // 市价单
-                   "STOP", // 止损单
-                   "STOP_MARKET", // 止损市价单
-                   "TAKE_PROFIT", // 止盈单
-                   "TAKE_PROFIT_MARKET", // 止盈暑市价单
-                   "TRAILING_STOP_MARKET" // 跟踪止损市价单
-               ],
-               "timeInForce": [ // 有效方式
-                   "GTC", // 成交为止, 一直有效
-                   "IOC", // 无法立即成交(吃单)的部分就撤销
-                   "FOK", // 无法全部立即成交就撤销
-                   "GTX" // 无法成为挂单方就撤销
-             ],
-             "liquidationFee": "0.010000",	// 强平费率
-               "marketTakeBound": "0.30",	// 市价吃单(相对于标记价格)允许可造成的最大价格偏离比例
-         }
-       ],
-    "timezone": "UTC" // 服务器所用的时间区域
-}
- */
+            ],
+            "timezone": "UTC"
+        }"#;
+        let info: FuturesExchangeInformation = serde_json::from_str(json).unwrap();
+        assert_eq!(info.assets[1].auto_asset_exchange, None);
+        let symbol = &info.symbols[0];
+        assert_eq!(symbol.contract_type, ContractType::Perpetual);
+        assert_eq!(symbol.status, ContractStatus::Trading);
+        assert_eq!(symbol.filters.len(), 7);
+        assert!(matches!(
+            symbol.filters[3],
+            FuturesFilters::MaxNumOrders { limit: 200 }
+        ));
+        assert_eq!(symbol.order_types.len(), 7);
+        assert_eq!(info.symbols[1].contract_type, ContractType::Unknown);
+        assert_eq!(info.symbols[1].status, ContractStatus::Close);
+        assert_eq!(info.symbols[1].order_types.len(), 1);
+        assert!(matches!(
+            info.symbols[1].filters[0],
+            FuturesFilters::Unknown
+        ));
+    }
+}
@@ -1,6 +1,5 @@
-use crate::{
-    endpoints::{public_enums::*, BaseRequest, Endpoint, EndpointRequest, SecurityType},
-    models::*,
+use crate::endpoints::{
+    public_enums::*, BaseRequest, Endpoint, EndpointRequest, Response, SecurityType,
 };
 
 use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
@@ -13,10 +12,46 @@ use strum::Display;
 pub enum FuturesTradingEP {
     #[endpoint(POST, Trade, url = "/fapi/v1/order")]
     Order,
+    #[endpoint(PUT, Trade, url = "/fapi/v1/order")]
+    ModifyOrder,
+    #[endpoint(DELETE, Trade, url = "/fapi/v1/order")]
+    CancelOrder,
+    #[endpoint(GET, UserData, url = "/fapi/v1/order")]
+    QueryOrder,
+    #[endpoint(DELETE, Trade, url = "/fapi/v1/allOpenOrders")]
+    CancelAllOpenOrders,
+    #[endpoint(GET, UserData, url = "/fapi/v1/openOrders")]
+    OpenOrders,
+    #[endpoint(GET, UserData, url = "/fapi/v1/allOrders")]
+    AllOrders,
+    #[endpoint(POST, Trade, url = "/fapi/v1/batchOrders")]
+    BatchOrders,
+    #[endpoint(PUT, Trade, url = "/fapi/v1/batchOrders")]
+    ModifyBatchOrders,
+    #[endpoint(DELETE, Trade, url = "/fapi/v1/batchOrders")]
+    CancelBatchOrders,
+    #[endpoint(POST, Trade, url = "/fapi/v1/countdownCancelAll")]
+    CountdownCancelAll,
+    #[endpoint(GET, UserData, url = "/fapi/v1/orderAmendment")]
+    OrderAmendment,
     #[endpoint(POST, Trade, url = "/fapi/v1/order/test")]
     OrderTest,
     #[endpoint(POST, Trade, url = "/fapi/v1/leverage")]
     Leverage,
+    #[endpoint(POST, Trade, url = "/fapi/v1/marginType")]
+    MarginType,
+    #[endpoint(POST, Trade, url = "/fapi/v1/positionSide/dual")]
+    ChangePositionMode,
+    #[endpoint(GET, UserData, url = "/fapi/v1/positionSide/dual")]
+    PositionMode,
+    #[endpoint(POST, Trade, url = "/fapi/v1/multiAssetsMargin")]
+    ChangeMultiAssetsMode,
+    #[endpoint(GET, UserData, url = "/fapi/v1/multiAssetsMargin")]
+    MultiAssetsMode,
+    #[endpoint(POST, Trade, url = "/fapi/v1/positionMargin")]
+    PositionMargin,
+    #[endpoint(GET, UserData, url = "/fapi/v1/positionMargin/history")]
+    PositionMarginHistory,
     #[endpoint(GET, UserData, url = "/fapi/v3/positionRisk")]
     PositionRiskV3,
     #[endpoint(GET, UserData, url = "/fapi/v1/userTrades")]
@@ -79,6 +114,8 @@ pub struct NewOrderRequest {
 #[serde(rename_all = "camelCase")]
 pub struct NewOrderResponse {
     pub client_order_id: String,
+    /// not returned when querying orders
+    #[serde(default)]
     pub cum_qty: String,
     pub cum_quote: String,
     pub executed_qty: String,
@@ -104,6 +141,8 @@ pub struct NewOrderResponse {
     pub price_match: String,
     pub self_trade_prevention_mode: String,
     pub good_till_date: u64,
+    /// only returned when querying orders
+    pub time: Option<u64>,
 }
 /*
 {"orderId":4052525244,"symbol":"SUIUSDC","status":"NEW","clientOrderId":"L4tpAzg0l78K5gp20HCAWK","price":"0.000000","avgPrice":"0.00","origQty":"3.0","executedQty":"0.0","cumQty":"0.0","cumQuote":"0.0000000","timeInForce":"GTC","type":"MARKET","reduceOnly":false,"closePosition":false,"side":"SELL","positionSide":"BOTH","stopPrice":"0.000000","workingType":"CONTRACT_PRICE","priceProtect":false,"origType":"MARKET","priceMatch":"NONE","selfTradePreventionMode":"EXPIRE_MAKER","goodTillDate":0,"updateTime":1751627587841}
@@ -113,38 +152,350 @@ impl EndpointRequest for NewOrderRequest {
     type Response = NewOrderResponse;
 
     fn validate(&self) -> anyhow::Result<()> {
-        match &self.r#type {
-            t @ FutureOrderType::Limit => {
-                if self.quantity.is_none() || self.time_in_force.is_none() || self.price.is_none() {
-                    anyhow::bail!("order type {t} requirements")
-                }
-            }
-            t @ FutureOrderType::Market => {
-                if self.quantity.is_none() {
-                    anyhow::bail!("order type {t} requirements")
-                }
-            }
-            t @ FutureOrderType::Stop | t @ FutureOrderType::TakeProfit => {
-                if self.quantity.is_none() || self.stop_price.is_none() || self.price.is_none() {
-                    anyhow::bail!("order type {t} requirements")
-                }
-            }
-            t @ FutureOrderType::StopMarket | t @ FutureOrderType::TakeProfitMarket => {
-                if self.stop_price.is_none() {
-                    anyhow::bail!("order type {t} requirements")
-                }
+        validate_order_type(
+            self.r#type,
+            self.quantity.is_some(),
+            self.time_in_force.is_some(),
+            self.price.is_some(),
+            self.stop_price.is_some(),
+            self.callback_rate.is_some(),
+        )
+    }
+}
+
+/// Mandatory parameters per order type, shared with the COIN-M orders.
+pub(crate) fn validate_order_type(
+    order_type: FutureOrderType,
+    quantity: bool,
+    time_in_force: bool,
+    price: bool,
+    stop_price: bool,
+    callback_rate: bool,
+) -> anyhow::Result<()> {
+    let fulfilled = match order_type {
+        FutureOrderType::Limit => quantity && time_in_force && price,
+        FutureOrderType::Market => quantity,
+        FutureOrderType::Stop | FutureOrderType::TakeProfit => quantity && stop_price && price,
+        FutureOrderType::StopMarket | FutureOrderType::TakeProfitMarket => stop_price,
+        FutureOrderType::TrailingStopMarket => callback_rate,
+    };
+    if !fulfilled {
+        anyhow::bail!("order type {order_type} requirements")
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: f64,
+    /// exactly one of `price` and `price_match` must be sent
+    pub price: Option<f64>,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub price_match: Option<PriceMatch>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for ModifyOrderRequest {
+    type Response = NewOrderResponse;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.price.is_some() == self.price_match.is_some() {
+            anyhow::bail!("exactly one of price and price_match must be sent")
+        }
+        validate_order_id(self.order_id, self.orig_client_order_id.as_deref())
+    }
+}
+
+/// Shared by cancel and query order.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderIdRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for OrderIdRequest {
+    type Response = NewOrderResponse;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_order_id(self.order_id, self.orig_client_order_id.as_deref())
+    }
+}
+
+pub(crate) fn validate_order_id(
+    order_id: Option<u64>,
+    orig_client_order_id: Option<&str>,
+) -> anyhow::Result<()> {
+    if order_id.is_none() && orig_client_order_id.is_none() {
+        anyhow::bail!("either order_id or orig_client_order_id must be sent")
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOpenOrdersRequest {
+    pub symbol: String,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for CancelAllOpenOrdersRequest {
+    type Response = CodeResponse;
+}
+
+/// Plain acknowledgement, e.g. `{"code": 200, "msg": "The operation of cancel all open order is done."}`
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CodeResponse {
+    pub code: i64,
+    pub msg: String,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersRequest {
+    /// orders of all symbols are returned when omitted, with a much higher weight
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for OpenOrdersRequest {
+    type Response = Vec<NewOrderResponse>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOrdersRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for AllOrdersRequest {
+    type Response = Vec<NewOrderResponse>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if let (Some(start_time), Some(end_time)) = (self.start_time, self.end_time) {
+            if end_time < start_time {
+                anyhow::bail!("start_time must be before end_time")
             }
-            t @ FutureOrderType::TrailingStopMarket => {
-                if self.callback_rate.is_none() {
-                    anyhow::bail!("order type {t} requirements")
-                }
+            if end_time - start_time > 86400000 * 7 {
+                anyhow::bail!(
+                    "The time between startTime and endTime cannot be longer than 7 days."
+                )
             }
         }
+        if self.limit.is_some_and(|limit| limit > 1000) {
+            anyhow::bail!("limit max 1000")
+        }
+        Ok(())
+    }
+}
+
+/// The orders are sent as a JSON list, at most 5 per request.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrdersRequest {
+    #[serde(serialize_with = "serialize_batch")]
+    pub batch_orders: Vec<NewOrderRequest>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for BatchOrdersRequest {
+    type Response = Vec<Response<NewOrderResponse>>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.batch_orders.is_empty() || self.batch_orders.len() > 5 {
+            anyhow::bail!("batch_orders takes 1 to 5 orders")
+        }
+        self.batch_orders
+            .iter()
+            .try_for_each(|order| order.validate())
+    }
+}
+
+/// The modifications are sent as a JSON list, at most 5 per request.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyBatchOrdersRequest {
+    #[serde(serialize_with = "serialize_batch")]
+    pub batch_orders: Vec<ModifyOrderRequest>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for ModifyBatchOrdersRequest {
+    type Response = Vec<Response<NewOrderResponse>>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.batch_orders.is_empty() || self.batch_orders.len() > 5 {
+            anyhow::bail!("batch_orders takes 1 to 5 orders")
+        }
+        self.batch_orders
+            .iter()
+            .try_for_each(|order| order.validate())
+    }
+}
+
+/// Batch items are encoded as a JSON list of objects with string values and
+/// without the per-request `timestamp`/`recvWindow`.
+pub(crate) fn serialize_batch<T, S>(items: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: serde::Serializer,
+{
+    let items = items
+        .iter()
+        .map(|item| {
+            let serde_json::Value::Object(map) =
+                serde_json::to_value(item).map_err(serde::ser::Error::custom)?
+            else {
+                return Err(serde::ser::Error::custom("batch item must be an object"));
+            };
+            Ok(map
+                .into_iter()
+                .filter(|(k, v)| !v.is_null() && k != "timestamp" && k != "recvWindow")
+                .map(|(k, v)| match v {
+                    serde_json::Value::String(_) => (k, v),
+                    v => (k, serde_json::Value::String(v.to_string())),
+                })
+                .collect::<serde_json::Map<_, _>>())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let json = serde_json::to_string(&items).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_str(&json)
+}
+
+/// Either `order_id_list` (max 10) or `orig_client_order_id_list` (max 10) must be sent.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelBatchOrdersRequest {
+    pub symbol: String,
+    #[serde(serialize_with = "serialize_json_list")]
+    pub order_id_list: Option<Vec<u64>>,
+    #[serde(serialize_with = "serialize_json_list")]
+    pub orig_client_order_id_list: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for CancelBatchOrdersRequest {
+    type Response = Vec<Response<NewOrderResponse>>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        match (&self.order_id_list, &self.orig_client_order_id_list) {
+            (Some(ids), None) if !ids.is_empty() && ids.len() <= 10 => Ok(()),
+            (None, Some(ids)) if !ids.is_empty() && ids.len() <= 10 => Ok(()),
+            _ => anyhow::bail!(
+                "exactly one of order_id_list and orig_client_order_id_list with 1 to 10 ids"
+            ),
+        }
+    }
+}
+
+pub(crate) fn serialize_json_list<T, S>(
+    list: &Option<Vec<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: serde::Serializer,
+{
+    match list {
+        Some(list) => serializer
+            .serialize_some(&serde_json::to_string(list).map_err(serde::ser::Error::custom)?),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Cancels all open orders of the symbol when the countdown expires,
+/// `countdown_time` of 0 cancels the timer. Meant to be called as a heartbeat.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAllRequest {
+    pub symbol: String,
+    /// milliseconds
+    pub countdown_time: u64,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    pub symbol: String,
+    pub countdown_time: String,
+}
+
+impl EndpointRequest for CountdownCancelAllRequest {
+    type Response = CountdownCancelAll;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendmentRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for OrderAmendmentRequest {
+    type Response = Vec<OrderAmendment>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_order_id(self.order_id, self.orig_client_order_id.as_deref())?;
+        if self.limit.is_some_and(|limit| limit > 100) {
+            anyhow::bail!("limit max 100")
+        }
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub amendment_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub time: u64,
+    pub amendment: Amendment,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Amendment {
+    pub price: AmendedValue,
+    pub orig_qty: AmendedValue,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AmendedValue {
+    pub before: String,
+    pub after: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum PositionSide {
@@ -276,6 +627,181 @@ impl EndpointRequest for LeverageRequest {
     type Response = LeverageResponse;
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum MarginType {
+    Isolated,
+    Crossed,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTypeRequest {
+    pub symbol: String,
+    pub margin_type: MarginType,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for MarginTypeRequest {
+    type Response = CodeResponse;
+}
+
+/// Switches between hedge mode (`true`) and one-way mode (`false`) on every symbol.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePositionModeRequest {
+    pub dual_side_position: bool,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for ChangePositionModeRequest {
+    type Response = CodeResponse;
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct PositionModeRequest(pub BaseRequest);
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMode {
+    /// `true` for hedge mode, `false` for one-way mode
+    pub dual_side_position: bool,
+}
+
+impl EndpointRequest for PositionModeRequest {
+    type Response = PositionMode;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeMultiAssetsModeRequest {
+    pub multi_assets_margin: bool,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+impl EndpointRequest for ChangeMultiAssetsModeRequest {
+    type Response = CodeResponse;
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct MultiAssetsModeRequest(pub BaseRequest);
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiAssetsMode {
+    pub multi_assets_margin: bool,
+}
+
+impl EndpointRequest for MultiAssetsModeRequest {
+    type Response = MultiAssetsMode;
+}
+
+/// Sent and returned as `1` (add) and `2` (reduce).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionMarginType {
+    Add,
+    Reduce,
+}
+
+impl Serialize for PositionMarginType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(match self {
+            PositionMarginType::Add => 1,
+            PositionMarginType::Reduce => 2,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PositionMarginType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match u8::deserialize(deserializer)? {
+            1 => Ok(PositionMarginType::Add),
+            2 => Ok(PositionMarginType::Reduce),
+            v => Err(serde::de::Error::custom(format!(
+                "invalid position margin type {v}"
+            ))),
+        }
+    }
+}
+
+/// Only for isolated positions.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginRequest {
+    pub symbol: String,
+    /// must be sent in hedge mode
+    pub position_side: Option<PositionSide>,
+    pub amount: f64,
+    pub r#type: PositionMarginType,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionMarginResponse {
+    pub amount: f64,
+    pub code: i64,
+    pub msg: String,
+    pub r#type: PositionMarginType,
+}
+
+impl EndpointRequest for PositionMarginRequest {
+    type Response = PositionMarginResponse;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginHistoryRequest {
+    pub symbol: String,
+    pub r#type: Option<PositionMarginType>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginChange {
+    pub symbol: String,
+    pub r#type: PositionMarginType,
+    /// not returned by COIN-M futures
+    #[serde(default)]
+    pub delta_type: String,
+    pub amount: String,
+    pub asset: String,
+    pub time: u64,
+    pub position_side: PositionSide,
+}
+
+impl EndpointRequest for PositionMarginHistoryRequest {
+    type Response = Vec<PositionMarginChange>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if let (Some(start_time), Some(end_time)) = (self.start_time, self.end_time) {
+            if end_time < start_time {
+                anyhow::bail!("start_time must be before end_time")
+            }
+            if end_time - start_time > 86400000 * 30 {
+                anyhow::bail!(
+                    "The time between startTime and endTime cannot be longer than 30 days."
+                )
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
 #[serde(rename_all = "camelCase")]
 pub struct PositionRiskV3Request {
@@ -372,12 +898,12 @@ impl EndpointRequest for UserTradesRequest {
     type Response = Vec<UserTrade>;
 
     fn validate(&self) -> anyhow::Result<()> {
-        if self.end_time.is_some() && self.start_time.is_some() {
-            if self.end_time.unwrap() < self.start_time.unwrap() {
+        if let (Some(start_time), Some(end_time)) = (self.start_time, self.end_time) {
+            if end_time < start_time {
                 anyhow::bail!("start_time must be before end_time")
             }
 
-            if self.end_time.unwrap() - self.start_time.unwrap() > 87400000 * 7 {
+            if end_time - start_time > 87400000 * 7 {
                 anyhow::bail!(
                     "The time between startTime and endTime cannot be longer than 7 days."
                 )
@@ -392,3 +918,87 @@ impl EndpointRequest for UserTradesRequest {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_orders_query() {
+        let mut order = NewOrderRequest::init(
+            "BTCUSDT".to_string(),
+            OrderSide::Buy,
+            FutureOrderType::Limit,
+            BaseRequest::init(),
+        );
+        order.quantity = Some(0.5);
+        order.price = Some(60000.0);
+        order.time_in_force = Some(TimeInForce::Gtc);
+        order.reduce_only = Some(false);
+        let req = BatchOrdersRequest::init(vec![order], BaseRequest::init());
+        let query = req.to_string();
+        let params: std::collections::HashMap<String, String> = serde_qs::from_str(&query).unwrap();
+        let batch: Vec<serde_json::Map<String, serde_json::Value>> =
+            serde_json::from_str(&params["batchOrders"]).unwrap();
+        assert_eq!(batch[0]["symbol"], "BTCUSDT");
+        assert_eq!(batch[0]["quantity"], "0.5");
+        assert_eq!(batch[0]["reduceOnly"], "false");
+        assert!(!batch[0].contains_key("timestamp"));
+        assert!(query.contains("&timestamp="));
+
+        let req = CancelBatchOrdersRequest {
+            symbol: "BTCUSDT".to_string(),
+            order_id_list: Some(vec![1, 2]),
+            orig_client_order_id_list: None,
+            base: BaseRequest::init(),
+        };
+        assert!(req
+            .to_string()
+            .starts_with("symbol=BTCUSDT&orderIdList=%5B1%2C2%5D&"));
+        assert!(req.validate().is_ok());
+
+        let mut modify = ModifyOrderRequest::init(
+            "BTCUSDT".to_string(),
+            OrderSide::Buy,
+            0.5,
+            BaseRequest::init(),
+        );
+        modify.order_id = Some(1);
+        assert!(modify.validate().is_err());
+        modify.price_match = Some(PriceMatch::Queue);
+        assert!(modify.validate().is_ok());
+        assert!(modify.to_string().contains("quantity=0.5&"));
+        assert!(!modify.to_string().contains("price="));
+        modify.price = Some(60000.0);
+        assert!(modify.validate().is_err());
+    }
+
+    #[test]
+    fn test_batch_orders_response() {
+        let json = r#"[
+            {"orderId":4052525244,"symbol":"SUIUSDC","status":"NEW","clientOrderId":"L4tpAzg0l78K5gp20HCAWK","price":"0.000000","avgPrice":"0.00","origQty":"3.0","executedQty":"0.0","cumQty":"0.0","cumQuote":"0.0000000","timeInForce":"GTC","type":"MARKET","reduceOnly":false,"closePosition":false,"side":"SELL","positionSide":"BOTH","stopPrice":"0.000000","workingType":"CONTRACT_PRICE","priceProtect":false,"origType":"MARKET","priceMatch":"NONE","selfTradePreventionMode":"EXPIRE_MAKER","goodTillDate":0,"updateTime":1751627587841},
+            {"code":-2022,"msg":"ReduceOnly Order is rejected."}
+        ]"#;
+        let resp: Vec<Response<NewOrderResponse>> = serde_json::from_str(json).unwrap();
+        assert!(matches!(&resp[0], Response::Data(order) if order.order_id == 4052525244));
+        assert!(matches!(&resp[1], Response::Error { code: -2022, .. }));
+    }
+
+    #[test]
+    fn test_position_margin() {
+        let req = PositionMarginRequest::init(
+            "BTCUSDT".to_string(),
+            100.0,
+            PositionMarginType::Reduce,
+            BaseRequest::init(),
+        );
+        assert!(req
+            .to_string()
+            .starts_with("symbol=BTCUSDT&amount=100&type=2&"));
+
+        let json =
+            r#"{"amount":100.0,"code":200,"msg":"Successfully modify position margin.","type":1}"#;
+        let resp: PositionMarginResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.r#type, PositionMarginType::Add);
+    }
+}
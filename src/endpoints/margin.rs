@@ -1,12 +1,15 @@
 use crate::models::*;
 use crate::{
-    endpoints::{BaseRequest, Endpoint, SecurityType},
+    endpoints::{
+        public_enums::OrderSide,
+        spot_trading::{SelfTradePreventionMode, SpotOrderType, TimeInForce},
+        BaseRequest, Endpoint, ResponseType, SecurityType,
+    },
     models::BnbBurnStatus,
 };
 use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
 
-use serde::Serialize;
-use serde_qs::to_string;
+use serde::{Deserialize, Serialize, Serializer};
 
 use super::{EndpointRequest, OneOrMany};
 
@@ -23,15 +26,94 @@ pub enum MarginEP {
     IsolatedMarginPairs,
     #[endpoint(GET, UserData, url = "/sapi/v1/margin/isolatedMarginData")]
     IsolatedMarginFeeData,
+    #[endpoint(POST, Margin, url = "/sapi/v1/margin/order")]
+    Order,
+    #[endpoint(DELETE, Margin, url = "/sapi/v1/margin/order")]
+    CancelOrder,
+    #[endpoint(POST, Margin, url = "/sapi/v1/margin/order/oco")]
+    OrderOco,
+    #[endpoint(POST, Margin, url = "/sapi/v1/margin/borrow-repay")]
+    BorrowRepay,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/borrow-repay")]
+    BorrowRepayRecords,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/account")]
+    CrossMarginAccount,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/isolated/account")]
+    IsolatedMarginAccount,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/maxBorrowable")]
+    MaxBorrowable,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/maxTransferable")]
+    MaxTransferable,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/interestHistory")]
+    InterestHistory,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/forceLiquidationRec")]
+    ForceLiquidationRecords,
+    #[endpoint(POST, Margin, url = "/sapi/v1/margin/isolated/account")]
+    EnableIsolatedAccount,
+    #[endpoint(DELETE, Margin, url = "/sapi/v1/margin/isolated/account")]
+    DisableIsolatedAccount,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/isolated/accountLimit")]
+    IsolatedAccountLimit,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/isolated/pair")]
+    IsolatedMarginPair,
+    #[endpoint(POST, Margin, url = "/sapi/v1/margin/isolated/transfer")]
+    IsolatedTransfer,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/transfer")]
+    TransferHistory,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/isolatedMarginTier")]
+    IsolatedMarginTier,
+    #[endpoint(GET, UserData, url = "/sapi/v1/margin/interestRateHistory")]
+    InterestRateHistory,
 }
 
-#[derive(Debug, Serialize)]
-pub struct GetBnbBurnStatusRequest(pub BaseRequest);
-impl ToString for GetBnbBurnStatusRequest {
-    fn to_string(&self) -> String {
-        to_string(self).unwrap()
+/// How a margin order interacts with the loan: MARGIN_BUY borrows what is
+/// missing, AUTO_REPAY repays the loan with the proceeds, AUTO_BORROW_REPAY
+/// does both.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SideEffectType {
+    NoSideEffect,
+    MarginBuy,
+    AutoRepay,
+    AutoBorrowRepay,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum BorrowRepayType {
+    Borrow,
+    Repay,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IsolatedTransferAccount {
+    Spot,
+    IsolatedMargin,
+}
+
+/// ROLL_IN moves funds into margin, ROLL_OUT out of it.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginTransferDirection {
+    RollIn,
+    RollOut,
+}
+
+/// The margin endpoints expect "TRUE"/"FALSE" rather than serde's lowercase.
+fn serialize_is_isolated<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(true) => serializer.serialize_str("TRUE"),
+        Some(false) => serializer.serialize_str("FALSE"),
+        None => serializer.serialize_none(),
     }
 }
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct GetBnbBurnStatusRequest(pub BaseRequest);
 impl EndpointRequest for GetBnbBurnStatusRequest {
     type Response = BnbBurnStatus;
 }
@@ -78,3 +160,360 @@ pub struct IsolatedMarginFeeDataRequest {
 impl EndpointRequest for IsolatedMarginFeeDataRequest {
     type Response = OneOrMany<IsolatedMarginFee>;
 }
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrderRequest {
+    pub symbol: String,
+    #[serde(serialize_with = "serialize_is_isolated")]
+    pub is_isolated: Option<bool>,
+    pub side: OrderSide,
+    pub r#type: SpotOrderType,
+    pub quantity: Option<f64>,
+    pub quote_order_qty: Option<f64>,
+    pub price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub new_client_order_id: Option<String>,
+    pub iceberg_qty: Option<f64>,
+    pub new_order_resp_type: Option<ResponseType>,
+    pub side_effect_type: Option<SideEffectType>,
+    pub time_in_force: Option<TimeInForce>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub auto_repay_at_cancel: Option<bool>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for MarginOrderRequest {
+    type Response = MarginTransaction;
+}
+
+/// Either order_id or orig_client_order_id must be sent.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelMarginOrderRequest {
+    pub symbol: String,
+    #[serde(serialize_with = "serialize_is_isolated")]
+    pub is_isolated: Option<bool>,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub new_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for CancelMarginOrderRequest {
+    type Response = OrderCanceled;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.order_id.is_none() && self.orig_client_order_id.is_none() {
+            anyhow::bail!("either order_id or orig_client_order_id must be sent");
+        }
+        Ok(())
+    }
+}
+
+/// The limit order is placed at `price`, the stop-loss order triggers at
+/// `stop_price` and becomes a limit order at `stop_limit_price` if given.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOcoOrderRequest {
+    pub symbol: String,
+    #[serde(serialize_with = "serialize_is_isolated")]
+    pub is_isolated: Option<bool>,
+    pub list_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub quantity: f64,
+    pub limit_client_order_id: Option<String>,
+    pub price: f64,
+    pub limit_iceberg_qty: Option<f64>,
+    pub stop_client_order_id: Option<String>,
+    pub stop_price: f64,
+    pub stop_limit_price: Option<f64>,
+    pub stop_iceberg_qty: Option<f64>,
+    pub stop_limit_time_in_force: Option<TimeInForce>,
+    pub new_order_resp_type: Option<ResponseType>,
+    pub side_effect_type: Option<SideEffectType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub auto_repay_at_cancel: Option<bool>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for MarginOcoOrderRequest {
+    type Response = OrderList;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.stop_limit_price.is_some() && self.stop_limit_time_in_force.is_none() {
+            anyhow::bail!("stop_limit_time_in_force is required with stop_limit_price");
+        }
+        Ok(())
+    }
+}
+
+/// `symbol` is only used for isolated margin.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRequest {
+    pub asset: String,
+    #[serde(serialize_with = "serialize_is_isolated")]
+    pub is_isolated: Option<bool>,
+    pub symbol: Option<String>,
+    pub amount: f64,
+    pub r#type: BorrowRepayType,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for BorrowRepayRequest {
+    type Response = TransactionId;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.is_isolated == Some(true) && self.symbol.is_none() {
+            anyhow::bail!("symbol is required for isolated margin");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecordsRequest {
+    pub r#type: BorrowRepayType,
+    pub asset: Option<String>,
+    pub isolated_symbol: Option<String>,
+    pub tx_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for BorrowRepayRecordsRequest {
+    type Response = MarginRows<BorrowRepayRecord>;
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct CrossMarginAccountRequest(pub BaseRequest);
+impl EndpointRequest for CrossMarginAccountRequest {
+    type Response = CrossMarginAccount;
+}
+
+/// Up to 5 symbols, comma separated; all isolated accounts when omitted.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccountRequest {
+    pub symbols: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for IsolatedMarginAccountRequest {
+    type Response = IsolatedMarginAccount;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if let Some(symbols) = &self.symbols {
+            if symbols.split(',').count() > 5 {
+                anyhow::bail!("at most 5 symbols can be queried at once");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowableRequest {
+    pub asset: String,
+    pub isolated_symbol: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for MaxBorrowableRequest {
+    type Response = MaxBorrowable;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferableRequest {
+    pub asset: String,
+    pub isolated_symbol: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for MaxTransferableRequest {
+    type Response = MaxTransferable;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestHistoryRequest {
+    pub asset: Option<String>,
+    pub isolated_symbol: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for InterestHistoryRequest {
+    type Response = MarginRows<MarginInterest>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceLiquidationRecordsRequest {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub isolated_symbol: Option<String>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for ForceLiquidationRecordsRequest {
+    type Response = MarginRows<ForceLiquidationRecord>;
+}
+
+/// Used both to enable and to disable an isolated margin account.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedAccountRequest {
+    pub symbol: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for IsolatedAccountRequest {
+    type Response = IsolatedAccountStatus;
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct IsolatedAccountLimitRequest(pub BaseRequest);
+impl EndpointRequest for IsolatedAccountLimitRequest {
+    type Response = IsolatedAccountLimit;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPairRequest {
+    pub symbol: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for IsolatedMarginPairRequest {
+    type Response = MarginPair;
+}
+
+/// Moves `asset` between the spot wallet and the isolated margin account of
+/// `symbol`.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedTransferRequest {
+    pub asset: String,
+    pub symbol: String,
+    pub trans_from: IsolatedTransferAccount,
+    pub trans_to: IsolatedTransferAccount,
+    pub amount: f64,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for IsolatedTransferRequest {
+    type Response = TransactionId;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.trans_from == self.trans_to {
+            anyhow::bail!("trans_from and trans_to must differ");
+        }
+        Ok(())
+    }
+}
+
+/// Cross margin transfers unless `isolated_symbol` is set.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferHistoryRequest {
+    pub asset: Option<String>,
+    pub r#type: Option<MarginTransferDirection>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    pub isolated_symbol: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for TransferHistoryRequest {
+    type Response = MarginRows<MarginTransfer>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginTierRequest {
+    pub symbol: String,
+    pub tier: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for IsolatedMarginTierRequest {
+    type Response = Vec<IsolatedMarginTier>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestRateHistoryRequest {
+    pub asset: String,
+    pub vip_level: Option<i32>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for InterestRateHistoryRequest {
+    type Response = Vec<InterestRate>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_margin_order_query() {
+        let mut request = MarginOrderRequest::init(
+            "BTCUSDT".into(),
+            OrderSide::Buy,
+            SpotOrderType::Market,
+            BaseRequest::init(),
+        );
+        request.is_isolated = Some(true);
+        request.quantity = Some(0.01);
+        request.side_effect_type = Some(SideEffectType::AutoBorrowRepay);
+        let query = request.to_string();
+        assert!(query.starts_with(
+            "symbol=BTCUSDT&isIsolated=TRUE&side=BUY&type=MARKET&quantity=0.01&sideEffectType=AUTO_BORROW_REPAY&timestamp="
+        ));
+
+        let mut borrow = BorrowRepayRequest::init(
+            "USDT".into(),
+            10.0,
+            BorrowRepayType::Borrow,
+            BaseRequest::init(),
+        );
+        borrow.is_isolated = Some(true);
+        assert!(borrow.validate().is_err());
+        borrow.symbol = Some("BTCUSDT".into());
+        assert!(borrow.validate().is_ok());
+    }
+
+    #[test]
+    fn test_isolated_transfer() {
+        let request = IsolatedTransferRequest::init(
+            "USDT".into(),
+            "BTCUSDT".into(),
+            IsolatedTransferAccount::Spot,
+            IsolatedTransferAccount::IsolatedMargin,
+            25.0,
+            BaseRequest::init(),
+        );
+        assert!(request.validate().is_ok());
+        assert!(request.to_string().starts_with(
+            "asset=USDT&symbol=BTCUSDT&transFrom=SPOT&transTo=ISOLATED_MARGIN&amount=25&timestamp="
+        ));
+    }
+}
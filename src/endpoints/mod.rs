@@ -11,6 +11,12 @@ pub mod futures_market;
 pub mod futures_account;
 pub mod coin_m_futures;
 pub mod usd_m_futures;
+pub mod sub_account;
+pub mod simple_earn;
+pub mod portfolio_margin;
+pub mod options;
+pub mod fiat;
+pub mod crypto_loan;
 
 pub use http::Method;
 
@@ -103,7 +109,8 @@ pub enum ResponseType {
     Full,
 }
 
-#[derive(Deserialize)]
+/// Also used for the per-item results of batch endpoints.
+#[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 pub enum Response<T> {
     Error { code: i64, msg: String },
@@ -0,0 +1,651 @@
+//! European options on `eapi`. Use `ApiConfig::options_rest_api_endpoint` as
+//! the client base URL, and connect `ws_streams::UserStream` to
+//! `options_ws_endpoint` with a listen key from `OptionsEP::StartListenKey`.
+//! There is no options testnet, both endpoints are `None` in
+//! `ApiConfig::testnet()`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::{
+    futures_trading::{serialize_batch, serialize_json_list},
+    public_enums::OrderSide,
+    spot_market::KlineInterval,
+    spot_trading::TimeInForce,
+    BaseRequest, Endpoint, EndpointRequest, Response, ResponseType, SecurityType,
+};
+use crate::errors::BinanceApiError;
+use crate::models::{string_or_float, Asks, Bids, Success, UserDataStream};
+
+use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, APIEndPoint)]
+#[allow(dead_code)]
+pub enum OptionsEP {
+    #[endpoint(GET, None, url = "/eapi/v1/exchangeInfo")]
+    ExchangeInfo,
+    #[endpoint(GET, None, url = "/eapi/v1/depth")]
+    OrderBook,
+    #[endpoint(GET, None, url = "/eapi/v1/klines")]
+    Klines,
+    #[endpoint(GET, None, url = "/eapi/v1/mark")]
+    MarkPrice,
+    #[endpoint(GET, None, url = "/eapi/v1/index")]
+    IndexPrice,
+    #[endpoint(GET, None, url = "/eapi/v1/openInterest")]
+    OpenInterest,
+    #[endpoint(GET, None, url = "/eapi/v1/exerciseHistory")]
+    ExerciseHistory,
+    #[endpoint(POST, Trade, url = "/eapi/v1/order")]
+    Order,
+    #[endpoint(DELETE, Trade, url = "/eapi/v1/order")]
+    CancelOrder,
+    #[endpoint(GET, UserData, url = "/eapi/v1/openOrders")]
+    OpenOrders,
+    #[endpoint(POST, Trade, url = "/eapi/v1/batchOrders")]
+    BatchOrders,
+    #[endpoint(DELETE, Trade, url = "/eapi/v1/batchOrders")]
+    CancelBatchOrders,
+    #[endpoint(GET, UserData, url = "/eapi/v1/position")]
+    Position,
+    #[endpoint(GET, UserData, url = "/eapi/v1/account")]
+    Account,
+    #[endpoint(POST, UserStream, url = "/eapi/v1/listenKey")]
+    StartListenKey,
+    #[endpoint(PUT, UserStream, url = "/eapi/v1/listenKey")]
+    KeepaliveListenKey,
+    #[endpoint(DELETE, UserStream, url = "/eapi/v1/listenKey")]
+    CloseListenKey,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OptionSide {
+    Call,
+    Put,
+}
+
+/// An option symbol such as `BTC-241227-60000-C`: underlying, expiry date
+/// (YYMMDD), strike price and C(all) or P(ut).
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionSymbol {
+    pub underlying: String,
+    pub expiry: NaiveDate,
+    pub strike: f64,
+    pub side: OptionSide,
+}
+
+impl FromStr for OptionSymbol {
+    type Err = BinanceApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || BinanceApiError::InvalidOptionSymbol(s.to_string());
+        let [underlying, expiry, strike, side] =
+            <[&str; 4]>::try_from(s.split('-').collect::<Vec<_>>()).map_err(|_| invalid())?;
+        if underlying.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            underlying: underlying.to_string(),
+            expiry: NaiveDate::parse_from_str(expiry, "%y%m%d").map_err(|_| invalid())?,
+            strike: strike
+                .parse()
+                .ok()
+                .filter(|strike: &f64| *strike > 0.0)
+                .ok_or_else(invalid)?,
+            side: match side {
+                "C" => OptionSide::Call,
+                "P" => OptionSide::Put,
+                _ => return Err(invalid()),
+            },
+        })
+    }
+}
+
+impl fmt::Display for OptionSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match self.side {
+            OptionSide::Call => "C",
+            OptionSide::Put => "P",
+        };
+        write!(
+            f,
+            "{}-{}-{}-{}",
+            self.underlying,
+            self.expiry.format("%y%m%d"),
+            self.strike,
+            side
+        )
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct ExchangeInfoRequest;
+impl EndpointRequest for ExchangeInfoRequest {
+    type Response = OptionsExchangeInformation;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<OptionSymbolInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbolInfo {
+    pub symbol: String,
+    pub side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    pub underlying: String,
+    pub expiry_date: u64,
+    pub unit: u32,
+    #[serde(with = "string_or_float")]
+    pub maker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub min_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maintenance_margin: f64,
+    pub price_scale: u32,
+    pub quantity_scale: u32,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBookRequest {
+    pub symbol: String,
+    /// 10, 20, 50, 100, 500 or 1000
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for OrderBookRequest {
+    type Response = OptionsOrderBook;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OptionsOrderBook {
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinesRequest {
+    pub symbol: String,
+    pub interval: KlineInterval,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for KlinesRequest {
+    type Response = Vec<OptionsKline>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if matches!(
+            self.interval,
+            KlineInterval::_1s | KlineInterval::_8h | KlineInterval::_1M
+        ) {
+            anyhow::bail!(
+                "interval {} is not supported by options klines",
+                self.interval.as_str()
+            )
+        }
+        if self.limit.is_some_and(|limit| limit > 1500) {
+            anyhow::bail!("limit max 1500")
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsKline {
+    pub open_time: u64,
+    pub close_time: u64,
+    #[serde(with = "string_or_float")]
+    pub open: f64,
+    #[serde(with = "string_or_float")]
+    pub high: f64,
+    #[serde(with = "string_or_float")]
+    pub low: f64,
+    #[serde(with = "string_or_float")]
+    pub close: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub interval: String,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub taker_volume: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_amount: f64,
+}
+
+/// All symbols when `symbol` is omitted.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPriceRequest {
+    pub symbol: Option<String>,
+}
+impl EndpointRequest for MarkPriceRequest {
+    type Response = Vec<OptionMarkPrice>;
+}
+
+/// Mark price with the implied volatilities and greeks.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionMarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(rename = "bidIV", with = "string_or_float")]
+    pub bid_iv: f64,
+    #[serde(rename = "askIV", with = "string_or_float")]
+    pub ask_iv: f64,
+    #[serde(rename = "markIV", with = "string_or_float")]
+    pub mark_iv: f64,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub risk_free_interest: f64,
+}
+
+/// `underlying` is the index symbol, e.g. BTCUSDT.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPriceRequest {
+    pub underlying: String,
+}
+impl EndpointRequest for IndexPriceRequest {
+    type Response = OptionIndexPrice;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionIndexPrice {
+    pub time: u64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+}
+
+/// `expiration` is the expiry date as YYMMDD, e.g. 221225.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestRequest {
+    pub underlying_asset: String,
+    pub expiration: String,
+}
+impl EndpointRequest for OpenInterestRequest {
+    type Response = Vec<OptionOpenInterest>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionOpenInterest {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest: f64,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest_usd: f64,
+    /// milliseconds, sent as a string
+    pub timestamp: String,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseHistoryRequest {
+    pub underlying: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+impl EndpointRequest for ExerciseHistoryRequest {
+    type Response = Vec<ExerciseRecord>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseRecord {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    #[serde(with = "string_or_float")]
+    pub real_strike_price: f64,
+    pub expiry_date: u64,
+    /// REALISTIC_VALUE_STRICKEN or EXTRINSIC_VALUE_EXPIRED
+    pub strike_result: String,
+}
+
+/// Options only take LIMIT orders.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OptionOrderType {
+    #[default]
+    Limit,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub r#type: OptionOrderType,
+    pub quantity: f64,
+    pub price: f64,
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<bool>,
+    pub new_order_resp_type: Option<ResponseType>,
+    pub client_order_id: Option<String>,
+    pub is_mmp: Option<bool>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for NewOrderRequest {
+    type Response = OptionOrder;
+}
+
+/// Either order_id or client_order_id must be sent.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for CancelOrderRequest {
+    type Response = OptionOrder;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.order_id.is_none() && self.client_order_id.is_none() {
+            anyhow::bail!("either order_id or client_order_id must be sent");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersRequest {
+    pub symbol: Option<String>,
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for OpenOrdersRequest {
+    type Response = Vec<OptionOrder>;
+}
+
+/// The ACK response only carries the ids, the rest is defaulted.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionOrder {
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float", default)]
+    pub price: f64,
+    #[serde(with = "string_or_float", default)]
+    pub quantity: f64,
+    #[serde(with = "string_or_float", default)]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float", default)]
+    pub fee: f64,
+    #[serde(default)]
+    pub side: String,
+    #[serde(default)]
+    pub r#type: String,
+    #[serde(default)]
+    pub time_in_force: String,
+    #[serde(default)]
+    pub reduce_only: bool,
+    #[serde(default)]
+    pub post_only: bool,
+    pub create_time: u64,
+    #[serde(default)]
+    pub update_time: u64,
+    #[serde(default)]
+    pub status: String,
+    #[serde(with = "string_or_float", default)]
+    pub avg_price: f64,
+    pub client_order_id: Option<String>,
+    pub option_side: Option<OptionSide>,
+    pub quote_asset: Option<String>,
+    #[serde(default)]
+    pub mmp: bool,
+}
+
+/// At most 10 orders, sent as a JSON list.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrdersRequest {
+    #[serde(serialize_with = "serialize_batch")]
+    pub orders: Vec<NewOrderRequest>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for BatchOrdersRequest {
+    type Response = Vec<Response<OptionOrder>>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.orders.is_empty() || self.orders.len() > 10 {
+            anyhow::bail!("orders takes 1 to 10 orders")
+        }
+        Ok(())
+    }
+}
+
+/// Either `order_ids` or `client_order_ids` (max 10) must be sent.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelBatchOrdersRequest {
+    pub symbol: String,
+    #[serde(serialize_with = "serialize_json_list")]
+    pub order_ids: Option<Vec<u64>>,
+    #[serde(serialize_with = "serialize_json_list")]
+    pub client_order_ids: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for CancelBatchOrdersRequest {
+    type Response = Vec<Response<OptionOrder>>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        match (&self.order_ids, &self.client_order_ids) {
+            (Some(ids), None) if !ids.is_empty() && ids.len() <= 10 => Ok(()),
+            (None, Some(ids)) if !ids.is_empty() && ids.len() <= 10 => Ok(()),
+            _ => anyhow::bail!("exactly one of order_ids and client_order_ids with 1 to 10 ids"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRequest {
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for PositionRequest {
+    type Response = Vec<OptionPosition>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionPosition {
+    pub symbol: String,
+    /// LONG or SHORT
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub reducible_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_value: f64,
+    #[serde(with = "string_or_float")]
+    pub ror: f64,
+    #[serde(rename = "unrealizedPNL", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    #[serde(with = "string_or_float")]
+    pub position_cost: f64,
+    pub expiry_date: u64,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct AccountRequest(pub BaseRequest);
+impl EndpointRequest for AccountRequest {
+    type Response = OptionsAccount;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsAccount {
+    pub asset: Vec<OptionsAccountAsset>,
+    pub greek: Vec<OptionsGreek>,
+    pub time: u64,
+    /// NORMAL, MEDIUM, HIGH or REDUCE_ONLY
+    pub risk_level: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsAccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub equity: f64,
+    #[serde(with = "string_or_float")]
+    pub available: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(rename = "unrealizedPNL", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsGreek {
+    pub underlying: String,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+}
+
+/// Also used to keep the listen key alive.
+#[derive(Debug, APIRequestInit, APIRequestToString, Serialize, Deserialize)]
+pub struct ListenKeyRequest {}
+
+impl EndpointRequest for ListenKeyRequest {
+    type Response = UserDataStream;
+}
+
+#[derive(Debug, APIRequestInit, APIRequestToString, Serialize, Deserialize)]
+pub struct CloseListenKeyRequest {}
+
+impl EndpointRequest for CloseListenKeyRequest {
+    type Response = Success;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_option_symbol() {
+        let symbol: OptionSymbol = "BTC-241227-60000-C".parse().unwrap();
+        assert_eq!(symbol.underlying, "BTC");
+        assert_eq!(
+            symbol.expiry,
+            NaiveDate::from_ymd_opt(2024, 12, 27).unwrap()
+        );
+        assert_eq!(symbol.strike, 60000.0);
+        assert_eq!(symbol.side, OptionSide::Call);
+        assert_eq!(symbol.to_string(), "BTC-241227-60000-C");
+
+        let symbol: OptionSymbol = "DOGE-250131-0.35-P".parse().unwrap();
+        assert_eq!(symbol.strike, 0.35);
+        assert_eq!(symbol.side, OptionSide::Put);
+
+        for invalid in [
+            "BTC-241227-60000",
+            "BTC-241327-60000-C",
+            "BTC-241227-x-C",
+            "BTC-241227-60000-X",
+        ] {
+            assert!(invalid.parse::<OptionSymbol>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_option_models() {
+        let json = r#"[{"symbol":"BTC-200730-9000-C","markPrice":"1343.2883","bidIV":"1.40000077","askIV":"1.50000153","markIV":"1.45000000","delta":"0.55937056","theta":"3739.82509871","gamma":"0.00010969","vega":"978.58874732","highPriceLimit":"1618.241","lowPriceLimit":"1068.3356","riskFreeInterest":"0.1"}]"#;
+        let marks: Vec<OptionMarkPrice> = serde_json::from_str(json).unwrap();
+        assert_eq!(marks[0].mark_iv, 1.45);
+
+        let json = r#"{"orderId":4611875134427365377,"symbol":"BTC-200730-9000-C","price":"100","quantity":"1","executedQty":"0","fee":"0","side":"BUY","type":"LIMIT","timeInForce":"GTC","reduceOnly":false,"postOnly":false,"createTime":1592465880683,"updateTime":1566818724722,"status":"ACCEPTED","avgPrice":"0","clientOrderId":"","priceScale":2,"quantityScale":2,"optionSide":"CALL","quoteAsset":"USDT","mmp":false}"#;
+        let order: OptionOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(order.option_side, Some(OptionSide::Call));
+
+        let json = r#"{"orderId":4611875134427365377,"symbol":"BTC-200730-9000-C","createTime":1592465880683}"#;
+        assert!(serde_json::from_str::<OptionOrder>(json).is_ok());
+    }
+}
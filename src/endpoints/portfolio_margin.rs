@@ -0,0 +1,469 @@
+//! Portfolio Margin accounts trade UM (USD-M), CM (COIN-M) and cross margin
+//! from a single account through `papi`. Use
+//! `ApiConfig::portfolio_margin_rest_api_endpoint` as the client base URL, and
+//! connect `ws_streams::UserStream` to `portfolio_margin_ws_endpoint` with
+//! a listen key from `PortfolioMarginEP::StartListenKey`. There is no
+//! Portfolio Margin testnet, both endpoints are `None` in `ApiConfig::testnet()`.
+
+use super::{
+    futures_trading::{
+        FutureOrderType, PositionSide, PriceMatch, ResponseType, SelfTradePreventionMode,
+        TimeInForce,
+    },
+    margin::SideEffectType,
+    public_enums::OrderSide,
+    spot_trading::{self, SpotOrderType},
+    BaseRequest, Endpoint, EndpointRequest, OneOrMany, SecurityType,
+};
+use crate::models::{
+    string_or_float, MarginTransaction, OrderCanceled, Success, TransactionId, UserDataStream,
+};
+
+use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, APIEndPoint)]
+#[allow(dead_code)]
+pub enum PortfolioMarginEP {
+    #[endpoint(POST, Trade, url = "/papi/v1/um/order")]
+    UmOrder,
+    #[endpoint(DELETE, Trade, url = "/papi/v1/um/order")]
+    UmCancelOrder,
+    #[endpoint(POST, Trade, url = "/papi/v1/cm/order")]
+    CmOrder,
+    #[endpoint(DELETE, Trade, url = "/papi/v1/cm/order")]
+    CmCancelOrder,
+    #[endpoint(POST, Trade, url = "/papi/v1/margin/order")]
+    MarginOrder,
+    #[endpoint(DELETE, Trade, url = "/papi/v1/margin/order")]
+    MarginCancelOrder,
+    #[endpoint(GET, UserData, url = "/papi/v1/balance")]
+    Balance,
+    #[endpoint(GET, UserData, url = "/papi/v1/account")]
+    Account,
+    #[endpoint(GET, UserData, url = "/papi/v1/um/positionRisk")]
+    UmPositionRisk,
+    #[endpoint(GET, UserData, url = "/papi/v1/cm/positionRisk")]
+    CmPositionRisk,
+    #[endpoint(POST, Trade, url = "/papi/v1/auto-collection")]
+    AutoCollection,
+    #[endpoint(POST, Trade, url = "/papi/v1/asset-collection")]
+    AssetCollection,
+    #[endpoint(POST, Trade, url = "/papi/v1/bnb-transfer")]
+    BnbTransfer,
+    #[endpoint(POST, UserStream, url = "/papi/v1/listenKey")]
+    StartListenKey,
+    #[endpoint(PUT, UserStream, url = "/papi/v1/listenKey")]
+    KeepaliveListenKey,
+    #[endpoint(DELETE, UserStream, url = "/papi/v1/listenKey")]
+    CloseListenKey,
+}
+
+/// UM and CM orders only take LIMIT and MARKET; conditional orders have
+/// their own endpoints.
+fn validate_futures_order(
+    order_type: FutureOrderType,
+    time_in_force: bool,
+    price: bool,
+) -> anyhow::Result<()> {
+    match order_type {
+        FutureOrderType::Limit if !(time_in_force && price) => {
+            anyhow::bail!("LIMIT orders need time_in_force and price")
+        }
+        FutureOrderType::Limit | FutureOrderType::Market => Ok(()),
+        _ => anyhow::bail!("only LIMIT and MARKET orders are supported"),
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct UmOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub position_side: Option<PositionSide>,
+    pub r#type: FutureOrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: f64,
+    pub reduce_only: Option<bool>,
+    pub price: Option<f64>,
+    pub new_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<ResponseType>,
+    pub price_match: Option<PriceMatch>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub good_till_date: Option<u64>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for UmOrderRequest {
+    type Response = PmOrder;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_futures_order(
+            self.r#type,
+            self.time_in_force.is_some(),
+            self.price.is_some(),
+        )
+    }
+}
+
+/// `quantity` is a number of contracts.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CmOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub position_side: Option<PositionSide>,
+    pub r#type: FutureOrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: u64,
+    pub reduce_only: Option<bool>,
+    pub price: Option<f64>,
+    pub new_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<ResponseType>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for CmOrderRequest {
+    type Response = PmOrder;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_futures_order(
+            self.r#type,
+            self.time_in_force.is_some(),
+            self.price.is_some(),
+        )
+    }
+}
+
+/// Cancels a UM or CM order. Either order_id or orig_client_order_id must
+/// be sent.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for CancelOrderRequest {
+    type Response = PmOrder;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.order_id.is_none() && self.orig_client_order_id.is_none() {
+            anyhow::bail!("either order_id or orig_client_order_id must be sent");
+        }
+        Ok(())
+    }
+}
+
+/// UM orders report `cum_quote`, CM orders `cum_base` and `pair`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PmOrder {
+    pub client_order_id: String,
+    #[serde(default)]
+    pub cum_qty: String,
+    pub cum_quote: Option<String>,
+    pub cum_base: Option<String>,
+    pub executed_qty: String,
+    pub order_id: u64,
+    pub avg_price: String,
+    pub orig_qty: String,
+    pub price: String,
+    pub reduce_only: bool,
+    pub side: String,
+    pub position_side: String,
+    pub status: String,
+    pub symbol: String,
+    pub pair: Option<String>,
+    pub time_in_force: String,
+    pub r#type: String,
+    pub self_trade_prevention_mode: Option<String>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub r#type: SpotOrderType,
+    pub quantity: Option<f64>,
+    pub quote_order_qty: Option<f64>,
+    pub price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub time_in_force: Option<spot_trading::TimeInForce>,
+    pub new_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<super::ResponseType>,
+    pub iceberg_qty: Option<f64>,
+    pub side_effect_type: Option<SideEffectType>,
+    pub self_trade_prevention_mode: Option<spot_trading::SelfTradePreventionMode>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for MarginOrderRequest {
+    type Response = MarginTransaction;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginCancelOrderRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub new_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for MarginCancelOrderRequest {
+    type Response = OrderCanceled;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.order_id.is_none() && self.orig_client_order_id.is_none() {
+            anyhow::bail!("either order_id or orig_client_order_id must be sent");
+        }
+        Ok(())
+    }
+}
+
+/// A single balance when `asset` is sent, all of them otherwise.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceRequest {
+    pub asset: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for BalanceRequest {
+    type Response = OneOrMany<PmBalance>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PmBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_asset: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_free: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_interest: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_locked: f64,
+    #[serde(with = "string_or_float")]
+    pub um_wallet_balance: f64,
+    #[serde(rename = "umUnrealizedPNL", with = "string_or_float")]
+    pub um_unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub cm_wallet_balance: f64,
+    #[serde(rename = "cmUnrealizedPNL", with = "string_or_float")]
+    pub cm_unrealized_pnl: f64,
+    pub update_time: u64,
+    #[serde(with = "string_or_float")]
+    pub negative_balance: f64,
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct AccountRequest(pub BaseRequest);
+impl EndpointRequest for AccountRequest {
+    type Response = PmAccount;
+}
+
+/// `uni_mmr` is the unified maintenance margin ratio; the account is
+/// liquidated when it falls to 1.05.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PmAccount {
+    #[serde(rename = "uniMMR", with = "string_or_float")]
+    pub uni_mmr: f64,
+    #[serde(with = "string_or_float")]
+    pub account_equity: f64,
+    #[serde(with = "string_or_float")]
+    pub actual_equity: f64,
+    #[serde(with = "string_or_float")]
+    pub account_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub account_maint_margin: f64,
+    pub account_status: String,
+    #[serde(with = "string_or_float")]
+    pub virtual_max_withdraw_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub total_available_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_open_loss: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct UmPositionRiskRequest {
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for UmPositionRiskRequest {
+    type Response = Vec<UmPositionRisk>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UmPositionRisk {
+    pub symbol: String,
+    pub position_side: String,
+    #[serde(with = "string_or_float")]
+    pub position_amt: f64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub un_realized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    #[serde(with = "string_or_float")]
+    pub max_notional_value: f64,
+    #[serde(with = "string_or_float")]
+    pub notional: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CmPositionRiskRequest {
+    pub margin_asset: Option<String>,
+    pub pair: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for CmPositionRiskRequest {
+    type Response = Vec<CmPositionRisk>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.margin_asset.is_some() && self.pair.is_some() {
+            anyhow::bail!("margin_asset and pair can't be sent together");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CmPositionRisk {
+    pub symbol: String,
+    pub position_side: String,
+    #[serde(with = "string_or_float")]
+    pub position_amt: f64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub un_realized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub notional_value: f64,
+    pub update_time: u64,
+}
+
+/// Collects the UM and CM wallet balances of every asset back into the
+/// margin wallet.
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct AutoCollectionRequest(pub BaseRequest);
+impl EndpointRequest for AutoCollectionRequest {
+    type Response = CollectionResponse;
+}
+
+/// Same as auto collection, for a single asset.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetCollectionRequest {
+    pub asset: String,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for AssetCollectionRequest {
+    type Response = CollectionResponse;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CollectionResponse {
+    pub msg: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BnbTransferSide {
+    ToUm,
+    FromUm,
+}
+
+/// Moves BNB between the margin and UM wallets, e.g. to pay UM fees in BNB.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct BnbTransferRequest {
+    pub amount: f64,
+    pub transfer_side: BnbTransferSide,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for BnbTransferRequest {
+    type Response = TransactionId;
+}
+
+/// Also used to keep the listen key alive.
+#[derive(Debug, APIRequestInit, APIRequestToString, Serialize, Deserialize)]
+pub struct ListenKeyRequest {}
+
+impl EndpointRequest for ListenKeyRequest {
+    type Response = UserDataStream;
+}
+
+#[derive(Debug, APIRequestInit, APIRequestToString, Serialize, Deserialize)]
+pub struct CloseListenKeyRequest {}
+
+impl EndpointRequest for CloseListenKeyRequest {
+    type Response = Success;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pm_orders() {
+        let mut request = UmOrderRequest::init(
+            "BTCUSDT".into(),
+            OrderSide::Buy,
+            FutureOrderType::Limit,
+            0.01,
+            BaseRequest::init(),
+        );
+        assert!(request.validate().is_err());
+        request.time_in_force = Some(TimeInForce::Gtc);
+        request.price = Some(30000.0);
+        assert!(request.validate().is_ok());
+        request.r#type = FutureOrderType::StopMarket;
+        assert!(request.validate().is_err());
+
+        let json = r#"{"clientOrderId":"testOrder","cumQty":"0","cumBase":"0","executedQty":"0","orderId":22542179,"avgPrice":"0.0","origQty":"10","price":"0","reduceOnly":false,"side":"BUY","positionSide":"SHORT","status":"NEW","symbol":"BTCUSD_200925","pair":"BTCUSD","timeInForce":"GTC","type":"MARKET","updateTime":1566818724722}"#;
+        let order: PmOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(order.pair.as_deref(), Some("BTCUSD"));
+        assert!(order.cum_quote.is_none());
+
+        let json = r#"{"uniMMR":"5167.92171923","accountEquity":"122607.35137903","actualEquity":"73.47428058","accountInitialMargin":"23.72469206","accountMaintMargin":"23.72469206","accountStatus":"NORMAL","virtualMaxWithdrawAmount":"1627523.32459208","totalAvailableBalance":"72.79170304","totalMarginOpenLoss":"0.00000000","updateTime":1657707212154}"#;
+        let account: PmAccount = serde_json::from_str(json).unwrap();
+        assert_eq!(account.account_status, "NORMAL");
+    }
+}
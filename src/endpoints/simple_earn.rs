@@ -0,0 +1,584 @@
+//! Simple Earn flexible and locked products.
+
+use std::collections::HashMap;
+
+use super::{spot_account::AccountRequest, BaseRequest, Endpoint, EndpointRequest, SecurityType};
+use crate::client::Client;
+use crate::errors::{BinanceApiError, Result};
+use crate::models::{string_or_float, string_or_float_opt};
+use crate::utils::ceil_to_decimals;
+
+use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, APIEndPoint)]
+#[allow(dead_code)]
+pub enum SimpleEarnEP {
+    #[endpoint(GET, UserData, url = "/sapi/v1/simple-earn/flexible/list")]
+    FlexibleList,
+    #[endpoint(GET, UserData, url = "/sapi/v1/simple-earn/locked/list")]
+    LockedList,
+    #[endpoint(POST, Trade, url = "/sapi/v1/simple-earn/flexible/subscribe")]
+    FlexibleSubscribe,
+    #[endpoint(POST, Trade, url = "/sapi/v1/simple-earn/locked/subscribe")]
+    LockedSubscribe,
+    #[endpoint(POST, Trade, url = "/sapi/v1/simple-earn/flexible/redeem")]
+    FlexibleRedeem,
+    #[endpoint(POST, Trade, url = "/sapi/v1/simple-earn/locked/redeem")]
+    LockedRedeem,
+    #[endpoint(GET, UserData, url = "/sapi/v1/simple-earn/flexible/position")]
+    FlexiblePosition,
+    #[endpoint(GET, UserData, url = "/sapi/v1/simple-earn/locked/position")]
+    LockedPosition,
+    #[endpoint(GET, UserData, url = "/sapi/v1/simple-earn/flexible/personalLeftQuota")]
+    FlexibleQuota,
+    #[endpoint(GET, UserData, url = "/sapi/v1/simple-earn/locked/personalLeftQuota")]
+    LockedQuota,
+    #[endpoint(
+        GET,
+        UserData,
+        url = "/sapi/v1/simple-earn/flexible/history/rewardsRecord"
+    )]
+    FlexibleRewards,
+    #[endpoint(
+        GET,
+        UserData,
+        url = "/sapi/v1/simple-earn/locked/history/rewardsRecord"
+    )]
+    LockedRewards,
+    #[endpoint(
+        GET,
+        UserData,
+        url = "/sapi/v1/simple-earn/flexible/subscriptionPreview"
+    )]
+    FlexiblePreview,
+    #[endpoint(GET, UserData, url = "/sapi/v1/simple-earn/locked/subscriptionPreview")]
+    LockedPreview,
+}
+
+/// Paged response of the list, position and history endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnPage<T> {
+    #[serde(default = "Vec::new")]
+    pub rows: Vec<T>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SourceAccount {
+    Spot,
+    Fund,
+    All,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DestAccount {
+    Spot,
+    Fund,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleProductListRequest {
+    pub asset: Option<String>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FlexibleProductListRequest {
+    type Response = EarnPage<FlexibleProduct>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleProduct {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    /// APR per balance tier, e.g. "0-5BTC"
+    #[serde(default)]
+    pub tier_annual_percentage_rate: HashMap<String, f64>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub air_drop_percentage_rate: Option<f64>,
+    pub can_purchase: bool,
+    pub can_redeem: bool,
+    pub is_sold_out: bool,
+    pub hot: bool,
+    #[serde(with = "string_or_float")]
+    pub min_purchase_amount: f64,
+    pub product_id: String,
+    pub subscription_start_time: u64,
+    pub status: String,
+}
+
+/// Same parameters as the flexible list.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductListRequest {
+    pub asset: Option<String>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for LockedProductListRequest {
+    type Response = EarnPage<LockedProduct>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProduct {
+    pub project_id: String,
+    pub detail: LockedProductDetail,
+    pub quota: LockedProductQuota,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductDetail {
+    pub asset: String,
+    pub reward_asset: String,
+    /// in days
+    pub duration: u32,
+    pub renewable: bool,
+    pub is_sold_out: bool,
+    #[serde(with = "string_or_float")]
+    pub apr: f64,
+    pub status: String,
+    pub subscription_start_time: u64,
+    pub extra_reward_asset: Option<String>,
+    #[serde(rename = "extraRewardAPR", with = "string_or_float_opt", default)]
+    pub extra_reward_apr: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductQuota {
+    #[serde(with = "string_or_float")]
+    pub total_personal_quota: f64,
+    #[serde(with = "string_or_float")]
+    pub minimum: f64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleSubscribeRequest {
+    pub product_id: String,
+    pub amount: f64,
+    pub auto_subscribe: Option<bool>,
+    pub source_account: Option<SourceAccount>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FlexibleSubscribeRequest {
+    type Response = SubscribeResponse;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedSubscribeRequest {
+    pub project_id: String,
+    pub amount: f64,
+    pub auto_subscribe: Option<bool>,
+    pub source_account: Option<SourceAccount>,
+    /// where the principal goes at maturity: SPOT or FLEXIBLE
+    pub redeem_to: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for LockedSubscribeRequest {
+    type Response = SubscribeResponse;
+}
+
+/// `position_id` is only returned for locked products.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeResponse {
+    pub purchase_id: u64,
+    pub position_id: Option<String>,
+    pub success: bool,
+}
+
+/// Either redeem_all or amount must be sent.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRedeemRequest {
+    pub product_id: String,
+    pub redeem_all: Option<bool>,
+    pub amount: Option<f64>,
+    pub dest_account: Option<DestAccount>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FlexibleRedeemRequest {
+    type Response = RedeemResponse;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.redeem_all != Some(true) && self.amount.is_none() {
+            anyhow::bail!("either redeem_all or amount must be sent");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRedeemRequest {
+    pub position_id: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for LockedRedeemRequest {
+    type Response = RedeemResponse;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RedeemResponse {
+    pub redeem_id: u64,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePositionRequest {
+    pub asset: Option<String>,
+    pub product_id: Option<String>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FlexiblePositionRequest {
+    type Response = EarnPage<FlexiblePosition>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePosition {
+    pub asset: String,
+    pub product_id: String,
+    #[serde(with = "string_or_float")]
+    pub total_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    #[serde(default)]
+    pub tier_annual_percentage_rate: HashMap<String, f64>,
+    pub can_redeem: bool,
+    #[serde(with = "string_or_float_opt", default)]
+    pub collateral_amount: Option<f64>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub cumulative_total_rewards: Option<f64>,
+    pub auto_subscribe: bool,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPositionRequest {
+    pub asset: Option<String>,
+    pub position_id: Option<String>,
+    pub project_id: Option<String>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for LockedPositionRequest {
+    type Response = EarnPage<LockedPosition>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPosition {
+    pub position_id: u64,
+    pub project_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub purchase_time: String,
+    pub duration: String,
+    pub accrual_days: String,
+    pub reward_asset: String,
+    #[serde(rename = "APY", with = "string_or_float")]
+    pub apy: f64,
+    #[serde(with = "string_or_float_opt", default)]
+    pub reward_amt: Option<f64>,
+    pub next_pay_date: Option<String>,
+    pub rewards_end_date: Option<String>,
+    pub deliver_date: Option<String>,
+    pub redeem_to: Option<String>,
+    pub can_redeem_early: bool,
+    pub auto_subscribe: bool,
+    pub r#type: String,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleQuotaRequest {
+    pub product_id: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FlexibleQuotaRequest {
+    type Response = PersonalLeftQuota;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedQuotaRequest {
+    pub project_id: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for LockedQuotaRequest {
+    type Response = PersonalLeftQuota;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalLeftQuota {
+    #[serde(with = "string_or_float")]
+    pub left_personal_quota: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum FlexibleRewardType {
+    Bonus,
+    Realtime,
+    Rewards,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRewardsRequest {
+    pub r#type: FlexibleRewardType,
+    pub product_id: Option<String>,
+    pub asset: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FlexibleRewardsRequest {
+    type Response = EarnPage<FlexibleReward>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleReward {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub rewards: f64,
+    pub project_id: Option<String>,
+    pub r#type: String,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRewardsRequest {
+    pub position_id: Option<String>,
+    pub asset: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for LockedRewardsRequest {
+    type Response = EarnPage<LockedReward>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedReward {
+    pub position_id: u64,
+    pub time: u64,
+    pub asset: String,
+    pub lock_period: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePreviewRequest {
+    pub product_id: String,
+    pub amount: f64,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FlexiblePreviewRequest {
+    type Response = FlexiblePreview;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePreview {
+    #[serde(with = "string_or_float")]
+    pub total_amount: f64,
+    pub reward_asset: String,
+    pub air_drop_asset: Option<String>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub est_daily_bonus_rewards: Option<f64>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub est_daily_real_time_rewards: Option<f64>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub est_daily_airdrop_rewards: Option<f64>,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPreviewRequest {
+    pub project_id: String,
+    pub amount: f64,
+    pub auto_subscribe: Option<bool>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for LockedPreviewRequest {
+    type Response = Vec<LockedPreview>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPreview {
+    pub reward_asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_reward_amt: f64,
+    pub extra_reward_asset: Option<String>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub est_total_extra_reward_amt: Option<f64>,
+    pub next_pay_date: Option<String>,
+    pub value_date: Option<String>,
+    pub rewards_end_date: Option<String>,
+    pub deliver_date: Option<String>,
+    pub next_subscription_date: Option<String>,
+}
+
+/// Amounts are planned in units of 1e-8, the precision the redeem endpoint
+/// accepts, so remainders don't pick up f64 noise.
+const UNITS: f64 = 1e8;
+
+/// Splits `shortfall` over the redeemable positions, in order. Each entry is
+/// the position and the amount to redeem from it, rounded up to 8 decimals.
+fn plan_redemptions(
+    positions: &[FlexiblePosition],
+    shortfall: f64,
+) -> Option<Vec<(&FlexiblePosition, f64)>> {
+    let mut left = (ceil_to_decimals(shortfall, 8) * UNITS).round() as i64;
+    let mut plan = vec![];
+    for position in positions.iter().filter(|p| p.can_redeem) {
+        if left <= 0 {
+            break;
+        }
+        let amount = left.min((position.total_amount * UNITS).round() as i64);
+        plan.push((position, amount as f64 / UNITS));
+        left -= amount;
+    }
+    (left <= 0).then_some(plan)
+}
+
+/// Redeems flexible positions of `asset` to the spot wallet until its free
+/// balance reaches `target`. Nothing is redeemed if the positions can't
+/// cover the shortfall. Returns the redeemed amount; if a redemption fails
+/// part way, `PartialRedemption` carries what was redeemed before it.
+pub async fn redeem_flexible_up_to(client: &Client, asset: &str, target: f64) -> Result<f64> {
+    let account = client
+        .access::<AccountRequest>(
+            &super::spot_account::SpotAccountEP::Account,
+            Some(AccountRequest::init(BaseRequest::init())),
+        )
+        .await?;
+    let free = account
+        .balances
+        .iter()
+        .find(|b| b.asset == asset)
+        .and_then(|b| b.free.parse::<f64>().ok())
+        .unwrap_or(0.0);
+    let shortfall = target - free;
+    if shortfall <= 0.0 {
+        return Ok(0.0);
+    }
+
+    let mut req = FlexiblePositionRequest::init(BaseRequest::init());
+    req.asset = Some(asset.to_string());
+    let positions = client
+        .access::<FlexiblePositionRequest>(&SimpleEarnEP::FlexiblePosition, Some(req))
+        .await?;
+    let plan = plan_redemptions(&positions.rows, shortfall).ok_or_else(|| {
+        BinanceApiError::Custom(format!(
+            "flexible positions can't cover {shortfall} {asset}"
+        ))
+    })?;
+
+    let mut redeemed = 0.0;
+    for (position, amount) in plan.iter() {
+        let mut req = FlexibleRedeemRequest::init(position.product_id.clone(), BaseRequest::init());
+        if *amount >= position.total_amount {
+            req.redeem_all = Some(true);
+        } else {
+            req.amount = Some(*amount);
+        }
+        req.dest_account = Some(DestAccount::Spot);
+        let result = client
+            .access::<FlexibleRedeemRequest>(&SimpleEarnEP::FlexibleRedeem, Some(req))
+            .await
+            .and_then(|response| match response.success {
+                true => Ok(()),
+                false => Err(BinanceApiError::Custom(format!(
+                    "redeeming {amount} from {} failed",
+                    position.product_id
+                ))),
+            });
+        if let Err(e) = result {
+            return Err(BinanceApiError::PartialRedemption {
+                redeemed,
+                source: Box::new(e),
+            });
+        }
+        redeemed += amount;
+    }
+    Ok(redeemed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flexible_redemption() {
+        let json = r#"{"rows":[{"totalAmount":"75.46","tierAnnualPercentageRate":{"0-5BTC":0.05,"5-10BTC":0.03},"latestAnnualPercentageRate":"0.02599895","yesterdayAirdropPercentageRate":"0.02599895","asset":"USDT","airDropAsset":"BETH","canRedeem":true,"collateralAmount":"232.23123213","productId":"USDT001","yesterdayRealTimeRewards":"0.10293829","cumulativeBonusRewards":"0.22759183","cumulativeRealTimeRewards":"0.22759183","cumulativeTotalRewards":"0.45459183","autoSubscribe":true},{"totalAmount":"10","latestAnnualPercentageRate":"0.01","asset":"USDT","canRedeem":false,"productId":"USDT002","autoSubscribe":false},{"totalAmount":"40","latestAnnualPercentageRate":"0.01","asset":"USDT","canRedeem":true,"productId":"USDT003","autoSubscribe":false}],"total":3}"#;
+        let positions: EarnPage<FlexiblePosition> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            positions.rows[0].tier_annual_percentage_rate["0-5BTC"],
+            0.05
+        );
+
+        let plan = plan_redemptions(&positions.rows, 100.0).unwrap();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].1, 75.46);
+        assert_eq!(plan[1].0.product_id, "USDT003");
+        assert_eq!(plan[1].1, 24.54);
+        assert_eq!(plan[1].1.to_string(), "24.54");
+        assert!(plan_redemptions(&positions.rows, 200.0).is_none());
+
+        let plan = plan_redemptions(&positions.rows, 75.460000001).unwrap();
+        assert_eq!(plan[1].1, 0.00000001);
+
+        let mut req = FlexibleRedeemRequest::init("USDT001".into(), BaseRequest::init());
+        assert!(req.validate().is_err());
+        req.redeem_all = Some(true);
+        assert!(req.validate().is_ok());
+    }
+}
@@ -26,6 +26,14 @@ pub enum SpotAccountEP {
     AllOrderList,
     #[endpoint(GET, UserData, url = "/api/v3/openOrderList")]
     OpenOrderList,
+    #[endpoint(GET, UserData, url = "/api/v3/myAllocations")]
+    Allocations,
+    #[endpoint(GET, UserData, url = "/api/v3/account/commission")]
+    Commission,
+    #[endpoint(GET, UserData, url = "/api/v3/rateLimit/order")]
+    UnfilledOrderCount,
+    #[endpoint(GET, UserData, url = "/api/v3/myPreventedMatches")]
+    PreventedMatches,
 
     // deprecated
     // #[endpoint(url = "/api/v3/userDataStream")]
@@ -73,3 +81,100 @@ pub struct QueryOrderRequest {
 impl EndpointRequest for QueryOrderRequest {
     type Response = Order;
 }
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderListRequest {
+    pub order_list_id: Option<i64>,
+    pub orig_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for QueryOrderListRequest {
+    type Response = OrderList;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.order_list_id.is_none() && self.orig_client_order_id.is_none() {
+            anyhow::bail!("either order_list_id or orig_client_order_id must be sent")
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOrderListRequest {
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for AllOrderListRequest {
+    type Response = Vec<OrderList>;
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct OpenOrderListRequest(pub BaseRequest);
+impl EndpointRequest for OpenOrderListRequest {
+    type Response = Vec<OrderList>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AllocationsRequest {
+    pub symbol: String,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub from_allocation_id: Option<u64>,
+    pub limit: Option<u16>,
+    pub order_id: Option<u64>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for AllocationsRequest {
+    type Response = Vec<Allocation>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRequest {
+    pub symbol: String,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for CommissionRequest {
+    type Response = AccountCommission;
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct UnfilledOrderCountRequest(pub BaseRequest);
+impl EndpointRequest for UnfilledOrderCountRequest {
+    type Response = Vec<UnfilledOrderCount>;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct PreventedMatchesRequest {
+    pub symbol: String,
+    pub prevented_match_id: Option<u64>,
+    pub order_id: Option<u64>,
+    pub from_prevented_match_id: Option<u64>,
+    pub limit: Option<u16>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for PreventedMatchesRequest {
+    type Response = Vec<PreventedMatch>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.prevented_match_id.is_none() && self.order_id.is_none() {
+            anyhow::bail!("either prevented_match_id or order_id must be sent")
+        }
+        if self.from_prevented_match_id.is_some() && self.order_id.is_none() {
+            anyhow::bail!("from_prevented_match_id can only be used together with order_id")
+        }
+        Ok(())
+    }
+}
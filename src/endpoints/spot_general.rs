@@ -1,12 +1,12 @@
 #![allow(dead_code)]
 
+use crate::client::Client;
 use crate::models::*;
 
 use super::EndpointRequest;
-use super::{Endpoint, OneOrManySymbol, SecurityType};
+use super::{BaseRequest, Endpoint, OneOrManySymbol, SecurityType};
 
-
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
 
@@ -19,6 +19,8 @@ pub enum SpotGeneralEP {
     Time,
     #[endpoint(GET, None, url = "/api/v3/exchangeInfo")]
     ExchangeInfo,
+    #[endpoint(GET, MarketData, url = "/sapi/v1/spot/delist-schedule")]
+    DelistSchedule,
 }
 
 #[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
@@ -29,3 +31,145 @@ pub struct ExchangeInfoRequest {
 impl EndpointRequest for ExchangeInfoRequest {
     type Response = ExchangeInformation;
 }
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct DelistScheduleRequest {
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for DelistScheduleRequest {
+    type Response = Vec<DelistSchedule>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DelistSchedule {
+    pub delist_time: u64,
+    pub symbols: Vec<String>,
+}
+
+/// A traded symbol that should no longer be quoted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolLifecycle {
+    pub symbol: String,
+    /// `None` once the symbol is gone from the exchange information
+    pub status: Option<SymbolStatus>,
+    pub delist_time: Option<u64>,
+}
+
+/// Picks the symbols that are in BREAK or HALT, missing from `info` or
+/// scheduled for delisting.
+pub fn lifecycle_report<S: AsRef<str>>(
+    symbols: &[S],
+    info: &ExchangeInformation,
+    schedule: &[DelistSchedule],
+) -> Vec<SymbolLifecycle> {
+    symbols
+        .iter()
+        .map(AsRef::as_ref)
+        .filter_map(|symbol| {
+            let status = info
+                .symbols
+                .iter()
+                .find(|s| s.symbol == symbol)
+                .map(|s| s.status);
+            let delist_time = schedule
+                .iter()
+                .filter(|d| d.symbols.iter().any(|s| s == symbol))
+                .map(|d| d.delist_time)
+                .min();
+            let stopped = matches!(
+                status,
+                Some(SymbolStatus::Break | SymbolStatus::Halt) | None
+            );
+            (stopped || delist_time.is_some()).then(|| SymbolLifecycle {
+                symbol: symbol.to_string(),
+                status,
+                delist_time,
+            })
+        })
+        .collect()
+}
+
+/// Fetches the exchange information and delist schedule and reports which
+/// of `symbols` are halted, gone or about to be delisted.
+pub async fn symbol_lifecycle_report<S: AsRef<str>>(
+    client: &Client,
+    symbols: &[S],
+) -> crate::errors::Result<Vec<SymbolLifecycle>> {
+    let info = client
+        .access(
+            &SpotGeneralEP::ExchangeInfo,
+            Some(ExchangeInfoRequest::init()),
+        )
+        .await?;
+    let schedule = client
+        .access(
+            &SpotGeneralEP::DelistSchedule,
+            Some(DelistScheduleRequest::init(BaseRequest::init())),
+        )
+        .await?;
+    Ok(lifecycle_report(symbols, &info, &schedule))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lifecycle_report() {
+        let json = r#"{"timezone":"UTC","serverTime":1565246363776,"rateLimits":[],"exchangeFilters":[],"symbols":[{"symbol":"ETHBTC","status":"TRADING","baseAsset":"ETH","baseAssetPrecision":8,"quoteAsset":"BTC","quotePrecision":8,"quoteAssetPrecision":8,"orderTypes":["LIMIT","MARKET"],"icebergAllowed":true,"ocoAllowed":true,"isSpotTradingAllowed":true,"isMarginTradingAllowed":true,"filters":[],"permissions":[],"permissionSets":[["SPOT","MARGIN","TRD_GRP_004"]],"defaultSelfTradePreventionMode":"NONE","allowedSelfTradePreventionModes":["NONE"]},{"symbol":"LTCBTC","status":"BREAK","baseAsset":"LTC","baseAssetPrecision":8,"quoteAsset":"BTC","quotePrecision":8,"orderTypes":["LIMIT"],"icebergAllowed":true,"isSpotTradingAllowed":true,"isMarginTradingAllowed":false,"filters":[]}]}"#;
+        let info: ExchangeInformation = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            info.symbols[0].permission_sets,
+            vec![vec![
+                AccountAndSymbolPermission::Spot,
+                AccountAndSymbolPermission::Margin,
+                AccountAndSymbolPermission::TrdGrp(4)
+            ]]
+        );
+        assert!(info.symbols[1].permission_sets.is_empty());
+
+        let json = r#"{"symbol":"NEWBTC","status":"SOME_NEW_STATUS","baseAsset":"NEW","baseAssetPrecision":8,"quoteAsset":"BTC","quotePrecision":8,"orderTypes":["LIMIT"],"icebergAllowed":true,"isSpotTradingAllowed":true,"isMarginTradingAllowed":false,"filters":[],"permissionSets":[["SPOT","SOME_NEW_PERMISSION"]]}"#;
+        let symbol: Symbol = serde_json::from_str(json).unwrap();
+        assert_eq!(symbol.status, SymbolStatus::Unknown);
+        assert_eq!(
+            symbol.permission_sets[0][1],
+            AccountAndSymbolPermission::Other("SOME_NEW_PERMISSION".to_string())
+        );
+
+        let json = r#"[{"delistTime":1686161202000,"symbols":["ETHBTC","BNBUSDT"]},{"delistTime":1686222232000,"symbols":["ADAUSDT"]}]"#;
+        let schedule: Vec<DelistSchedule> = serde_json::from_str(json).unwrap();
+
+        let report = lifecycle_report(&["ETHBTC", "LTCBTC", "XYZBTC"], &info, &schedule);
+        assert_eq!(
+            report,
+            vec![
+                SymbolLifecycle {
+                    symbol: "ETHBTC".to_string(),
+                    status: Some(SymbolStatus::Trading),
+                    delist_time: Some(1686161202000),
+                },
+                SymbolLifecycle {
+                    symbol: "LTCBTC".to_string(),
+                    status: Some(SymbolStatus::Break),
+                    delist_time: None,
+                },
+                SymbolLifecycle {
+                    symbol: "XYZBTC".to_string(),
+                    status: None,
+                    delist_time: None,
+                },
+            ]
+        );
+        assert_eq!(
+            ExchangeInfoRequest {
+                symbols: None,
+                permissions: Some(AccountAndSymbolPermission::TrdGrp(2)),
+            }
+            .to_string(),
+            "permissions=TRD_GRP_002"
+        );
+    }
+}
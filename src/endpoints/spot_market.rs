@@ -1,13 +1,12 @@
 #![allow(dead_code)]
 
+use crate::errors::BinanceApiError;
 use crate::models::*;
 
 use super::EndpointRequest;
 use super::{Endpoint, OneOrMany, OneOrManySymbol, SecurityType};
 
-use either::Either;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
 use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
 
@@ -25,10 +24,16 @@ pub enum SpotMarketEP {
     SymbolOrderBookTicker,
     #[endpoint(GET, None, url = "/api/v3/ticker/24hr")]
     Ticker24hr,
+    #[endpoint(GET, None, url = "/api/v3/ticker")]
+    RollingWindowTicker,
+    #[endpoint(GET, None, url = "/api/v3/ticker/tradingDay")]
+    TradingDayTicker,
     #[endpoint(GET, None, url = "/api/v3/aggTrades")]
     AggTrades,
     #[endpoint(GET, None, url = "/api/v3/klines")]
     Klines,
+    #[endpoint(GET, None, url = "/api/v3/uiKlines")]
+    UIKlines,
     #[endpoint(GET, None, url = "/api/v3/trades")]
     Trades,
     #[endpoint(GET, None, url = "/api/v3/historicalTrades")]
@@ -50,28 +55,122 @@ impl EndpointRequest for PriceTickerRequest {
     type Response = OneOrMany<SymbolPrice>;
 }
 
-#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
-#[serde(rename_all = "camelCase")]
-pub struct AveragePriceRequest {
-    pub symbol: String,
-}
-impl EndpointRequest for AveragePriceRequest {
-    type Response = AveragePrice;
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct BookTickerRequest(pub Option<OneOrManySymbol>);
+impl EndpointRequest for BookTickerRequest {
+    type Response = OneOrMany<Tickers>;
 }
 
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Default, APIRequestInit, APIRequestToString)]
 #[serde(rename_all = "camelCase")]
 pub struct Ticker24hRequest {
+    #[serde(flatten)]
     pub symbols: Option<OneOrManySymbol>,
-    pub r#type: Option<Ticker24hReqType>,
+    pub r#type: Option<TickerType>,
 }
-#[derive(Debug, Serialize)]
+impl EndpointRequest for Ticker24hRequest {
+    type Response = Ticker24hResponse;
+}
+pub type Ticker24hResponse = OneOrMany<PriceStats>;
+
+#[derive(Debug, Serialize, Clone, Copy)]
 #[serde(rename_all = "UPPERCASE")]
-pub enum Ticker24hReqType {
+pub enum TickerType {
     FULL,
     MINI,
 }
-pub type Ticker24hResponse = OneOrMany<Either<PriceStatsFull, PriceStatsMini>>;
+
+#[deprecated(note = "renamed to TickerType")]
+pub type Ticker24hReqType = TickerType;
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingWindowTickerRequest {
+    #[serde(flatten)]
+    pub symbols: OneOrManySymbol,
+    pub window_size: Option<WindowSize>,
+    pub r#type: Option<TickerType>,
+}
+impl EndpointRequest for RollingWindowTickerRequest {
+    type Response = OneOrMany<WindowPriceStats>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if let OneOrMany::Many(symbols) = &self.symbols {
+            if symbols.len() > 100 {
+                anyhow::bail!("max 100 symbols")
+            }
+        }
+        if let Some(window_size) = &self.window_size {
+            window_size.validate()?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingDayTickerRequest {
+    #[serde(flatten)]
+    pub symbols: OneOrManySymbol,
+    /// hours and minutes (e.g. "-1:00", "05:45") or only hours (e.g. "0", "8", "4"), defaults to 0 (UTC)
+    pub time_zone: Option<String>,
+    pub r#type: Option<TickerType>,
+}
+impl EndpointRequest for TradingDayTickerRequest {
+    type Response = OneOrMany<WindowPriceStats>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if let OneOrMany::Many(symbols) = &self.symbols {
+            if symbols.len() > 100 {
+                anyhow::bail!("max 100 symbols")
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rolling window size, defaults to 1d on the server side.
+///
+/// minutes: 1-59, hours: 1-23, days: 1-7
+#[derive(Debug, Clone, Copy)]
+pub enum WindowSize {
+    Minutes(u8),
+    Hours(u8),
+    Days(u8),
+}
+impl WindowSize {
+    fn validate(&self) -> anyhow::Result<()> {
+        match *self {
+            WindowSize::Minutes(m) if !(1..=59).contains(&m) => {
+                anyhow::bail!("window size minutes must be within 1-59")
+            }
+            WindowSize::Hours(h) if !(1..=23).contains(&h) => {
+                anyhow::bail!("window size hours must be within 1-23")
+            }
+            WindowSize::Days(d) if !(1..=7).contains(&d) => {
+                anyhow::bail!("window size days must be within 1-7")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+impl std::fmt::Display for WindowSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowSize::Minutes(m) => write!(f, "{}m", m),
+            WindowSize::Hours(h) => write!(f, "{}h", h),
+            WindowSize::Days(d) => write!(f, "{}d", d),
+        }
+    }
+}
+impl Serialize for WindowSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
 
 #[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
 #[serde(rename_all = "camelCase")]
@@ -84,10 +183,21 @@ pub struct KlinesRequest {
     pub limit: Option<u16>,
 }
 impl EndpointRequest for KlinesRequest {
-    type Response = Vec<Vec<Value>>;
+    type Response = Vec<KlineSummary>;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.limit.is_some_and(|limit| limit > 1000) {
+            anyhow::bail!("limit max 1000")
+        }
+        Ok(())
+    }
 }
 
-#[derive(Debug, Serialize)]
+/// uiKlines takes the same parameters as klines, only the candles are
+/// tuned for presentation.
+pub type UIKlinesRequest = KlinesRequest;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KlineInterval {
     #[serde(rename = "1s")]
     _1s,
@@ -123,6 +233,64 @@ pub enum KlineInterval {
     _1M,
 }
 
+impl KlineInterval {
+    /// The interval as used in query strings and stream names.
+    pub fn as_str(&self) -> &'static str {
+        use KlineInterval::*;
+
+        match self {
+            _1s => "1s",
+            _1m => "1m",
+            _3m => "3m",
+            _5m => "5m",
+            _15m => "15m",
+            _30m => "30m",
+            _1h => "1h",
+            _2h => "2h",
+            _4h => "4h",
+            _6h => "6h",
+            _8h => "8h",
+            _12h => "12h",
+            _1d => "1d",
+            _3d => "3d",
+            _1w => "1w",
+            _1M => "1M",
+        }
+    }
+}
+
+/// Fails for `1M`, a calendar month has no fixed duration.
+impl TryFrom<KlineInterval> for chrono::Duration {
+    type Error = BinanceApiError;
+
+    fn try_from(interval: KlineInterval) -> Result<Self, Self::Error> {
+        use KlineInterval::*;
+
+        Ok(match interval {
+            _1s => chrono::Duration::seconds(1),
+            _1m => chrono::Duration::minutes(1),
+            _3m => chrono::Duration::minutes(3),
+            _5m => chrono::Duration::minutes(5),
+            _15m => chrono::Duration::minutes(15),
+            _30m => chrono::Duration::minutes(30),
+            _1h => chrono::Duration::hours(1),
+            _2h => chrono::Duration::hours(2),
+            _4h => chrono::Duration::hours(4),
+            _6h => chrono::Duration::hours(6),
+            _8h => chrono::Duration::hours(8),
+            _12h => chrono::Duration::hours(12),
+            _1d => chrono::Duration::days(1),
+            _3d => chrono::Duration::days(3),
+            _1w => chrono::Duration::weeks(1),
+            _1M => {
+                return Err(BinanceApiError::Custom(
+                    "1M kline interval has no fixed duration".to_string(),
+                ))
+            }
+        })
+    }
+}
+
 #[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
 #[serde(rename_all = "camelCase")]
 pub struct AggTradesRequest {
@@ -142,13 +310,42 @@ pub struct AvgPriceRequest {
     pub symbol: String,
 }
 impl EndpointRequest for AvgPriceRequest {
-    type Response = AvgPriceResponse;
+    type Response = AveragePrice;
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct AvgPriceResponse {
-    pub mins: u16,
-    pub price: String,
-    pub close_time: u64,
+#[deprecated(note = "renamed to AvgPriceRequest")]
+pub type AveragePriceRequest = AvgPriceRequest;
+
+#[deprecated(note = "renamed to AveragePrice")]
+pub type AvgPriceResponse = AveragePrice;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL: &str = r#"{"symbol":"BNBBTC","priceChange":"-94.99999800","priceChangePercent":"-95.960","weightedAvgPrice":"0.29628482","prevClosePrice":"0.10002000","lastPrice":"4.00000200","lastQty":"200.00000000","bidPrice":"4.00000000","bidQty":"100.00000000","askPrice":"4.00000200","askQty":"100.00000000","openPrice":"99.00000000","highPrice":"100.00000000","lowPrice":"0.10000000","volume":"8913.30000000","quoteVolume":"15.30000000","openTime":1499783499040,"closeTime":1499869899040,"firstId":28385,"lastId":28460,"count":76}"#;
+    const MINI: &str = r#"{"symbol":"BNBBTC","openPrice":"99.00000000","highPrice":"100.00000000","lowPrice":"0.10000000","lastPrice":"4.00000200","volume":"8913.30000000","quoteVolume":"15.30000000","openTime":1499783499040,"closeTime":1499869899040,"firstId":28385,"lastId":28460,"count":76}"#;
+    const WINDOW_FULL: &str = r#"{"symbol":"BNBBTC","priceChange":"-8.00000000","priceChangePercent":"-88.889","weightedAvgPrice":"2.60427807","openPrice":"9.00000000","highPrice":"9.00000000","lowPrice":"1.00000000","lastPrice":"1.00000000","volume":"187.00000000","quoteVolume":"487.00000000","openTime":1641859200000,"closeTime":1642031999999,"firstId":0,"lastId":60,"count":61}"#;
+
+    #[test]
+    fn test_ticker_responses() {
+        let stats: Ticker24hResponse = serde_json::from_str(FULL).unwrap();
+        assert!(matches!(stats, OneOrMany::One(PriceStats::Full(s)) if s.bid_qty == 100.0));
+        let stats: Ticker24hResponse = serde_json::from_str(MINI).unwrap();
+        assert!(matches!(stats, OneOrMany::One(PriceStats::Mini(s)) if s.last_price == 4.000002));
+
+        let stats: Ticker24hResponse = serde_json::from_str(&format!("[{FULL},{FULL}]")).unwrap();
+        assert!(
+            matches!(&stats, OneOrMany::Many(v) if v.len() == 2 && matches!(v[1], PriceStats::Full(_)))
+        );
+        let stats: Ticker24hResponse = serde_json::from_str(&format!("[{MINI}]")).unwrap();
+        assert!(matches!(&stats, OneOrMany::Many(v) if matches!(v[0], PriceStats::Mini(_))));
+
+        let stats: OneOrMany<WindowPriceStats> = serde_json::from_str(WINDOW_FULL).unwrap();
+        assert!(matches!(stats, OneOrMany::One(WindowPriceStats::Full(s)) if s.count == 61));
+        let stats: OneOrMany<WindowPriceStats> =
+            serde_json::from_str(&format!("[{WINDOW_FULL},{MINI}]")).unwrap();
+        assert!(matches!(&stats, OneOrMany::Many(v)
+            if matches!(v[0], WindowPriceStats::Full(_)) && matches!(v[1], WindowPriceStats::Mini(_))));
+    }
 }
@@ -1,4 +1,6 @@
-use crate::endpoints::{public_enums::*, Endpoint, EndpointRequest, ResponseType, SecurityType};
+use crate::endpoints::{
+    public_enums::*, BaseRequest, Endpoint, EndpointRequest, ResponseType, SecurityType,
+};
 use crate::models::*;
 
 use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
@@ -12,9 +14,21 @@ pub enum SpotTradingEP {
     Order,
     #[endpoint(POST, Trade, url = "/api/v3/order/test")]
     OrderTest,
+    #[endpoint(POST, Trade, url = "/api/v3/orderList/oco")]
+    OrderListOco,
+    #[endpoint(POST, Trade, url = "/api/v3/orderList/oto")]
+    OrderListOto,
+    #[endpoint(POST, Trade, url = "/api/v3/orderList/otoco")]
+    OrderListOtoco,
+    #[endpoint(DELETE, Trade, url = "/api/v3/orderList")]
+    CancelOrderList,
+    #[endpoint(POST, Trade, url = "/api/v3/sor/order")]
+    SorOrder,
+    #[endpoint(POST, Trade, url = "/api/v3/sor/order/test")]
+    SorOrderTest,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
 pub enum SelfTradePreventionMode {
     #[serde(rename = "EXPIRE_TAKER")]
     ExpireTaker,
@@ -22,6 +36,8 @@ pub enum SelfTradePreventionMode {
     ExpireMaker,
     #[serde(rename = "EXPIRE_BOTH")]
     ExpireBoth,
+    #[serde(rename = "DECREMENT")]
+    Decrement,
     #[serde(rename = "NONE")]
     None,
 }
@@ -78,6 +94,204 @@ pub enum TimeInForce {
     Fok,
 }
 
+/// One-Cancels-the-Other: the above order is the one priced above the
+/// market, the below order the one priced under it.
+///
+/// above_type: STOP_LOSS_LIMIT, STOP_LOSS, LIMIT_MAKER, TAKE_PROFIT, TAKE_PROFIT_LIMIT
+/// below_type: STOP_LOSS_LIMIT, STOP_LOSS, LIMIT_MAKER, TAKE_PROFIT, TAKE_PROFIT_LIMIT
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOcoOrderListRequest {
+    pub symbol: String,
+    pub list_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub quantity: f64,
+    pub above_type: SpotOrderType,
+    pub above_client_order_id: Option<String>,
+    pub above_iceberg_qty: Option<f64>,
+    pub above_price: Option<f64>,
+    pub above_stop_price: Option<f64>,
+    pub above_trailing_delta: Option<i64>,
+    pub above_time_in_force: Option<TimeInForce>,
+    pub above_strategy_id: Option<i32>,
+    pub above_strategy_type: Option<i32>,
+    pub below_type: SpotOrderType,
+    pub below_client_order_id: Option<String>,
+    pub below_iceberg_qty: Option<f64>,
+    pub below_price: Option<f64>,
+    pub below_stop_price: Option<f64>,
+    pub below_trailing_delta: Option<i64>,
+    pub below_time_in_force: Option<TimeInForce>,
+    pub below_strategy_id: Option<i32>,
+    pub below_strategy_type: Option<i32>,
+    pub new_order_resp_type: Option<ResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for NewOcoOrderListRequest {
+    type Response = OrderList;
+}
+
+/// One-Triggers-the-Other: the pending order is only placed once the
+/// working order is fully filled.
+///
+/// working_type: LIMIT, LIMIT_MAKER
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOtoOrderListRequest {
+    pub symbol: String,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<ResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub working_type: SpotOrderType,
+    pub working_side: OrderSide,
+    pub working_client_order_id: Option<String>,
+    pub working_price: f64,
+    pub working_quantity: f64,
+    pub working_iceberg_qty: Option<f64>,
+    pub working_time_in_force: Option<TimeInForce>,
+    pub working_strategy_id: Option<i32>,
+    pub working_strategy_type: Option<i32>,
+    pub pending_type: SpotOrderType,
+    pub pending_side: OrderSide,
+    pub pending_client_order_id: Option<String>,
+    pub pending_price: Option<f64>,
+    pub pending_stop_price: Option<f64>,
+    pub pending_trailing_delta: Option<i64>,
+    pub pending_quantity: f64,
+    pub pending_iceberg_qty: Option<f64>,
+    pub pending_time_in_force: Option<TimeInForce>,
+    pub pending_strategy_id: Option<i32>,
+    pub pending_strategy_type: Option<i32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for NewOtoOrderListRequest {
+    type Response = OrderList;
+}
+
+/// One-Triggers-a-One-Cancels-the-Other: the pending OCO pair is only placed
+/// once the working order is fully filled.
+///
+/// working_type: LIMIT, LIMIT_MAKER
+/// pending_above_type: LIMIT_MAKER, STOP_LOSS, STOP_LOSS_LIMIT
+/// pending_below_type: LIMIT_MAKER, STOP_LOSS, STOP_LOSS_LIMIT
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOtocoOrderListRequest {
+    pub symbol: String,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<ResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub working_type: SpotOrderType,
+    pub working_side: OrderSide,
+    pub working_client_order_id: Option<String>,
+    pub working_price: f64,
+    pub working_quantity: f64,
+    pub working_iceberg_qty: Option<f64>,
+    pub working_time_in_force: Option<TimeInForce>,
+    pub working_strategy_id: Option<i32>,
+    pub working_strategy_type: Option<i32>,
+    pub pending_side: OrderSide,
+    pub pending_quantity: f64,
+    pub pending_above_type: SpotOrderType,
+    pub pending_above_client_order_id: Option<String>,
+    pub pending_above_price: Option<f64>,
+    pub pending_above_stop_price: Option<f64>,
+    pub pending_above_trailing_delta: Option<i64>,
+    pub pending_above_iceberg_qty: Option<f64>,
+    pub pending_above_time_in_force: Option<TimeInForce>,
+    pub pending_above_strategy_id: Option<i32>,
+    pub pending_above_strategy_type: Option<i32>,
+    pub pending_below_type: Option<SpotOrderType>,
+    pub pending_below_client_order_id: Option<String>,
+    pub pending_below_price: Option<f64>,
+    pub pending_below_stop_price: Option<f64>,
+    pub pending_below_trailing_delta: Option<i64>,
+    pub pending_below_iceberg_qty: Option<f64>,
+    pub pending_below_time_in_force: Option<TimeInForce>,
+    pub pending_below_strategy_id: Option<i32>,
+    pub pending_below_strategy_type: Option<i32>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for NewOtocoOrderListRequest {
+    type Response = OrderList;
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderListRequest {
+    pub symbol: String,
+    pub order_list_id: Option<i64>,
+    pub list_client_order_id: Option<String>,
+    pub new_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for CancelOrderListRequest {
+    type Response = OrderList;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.order_list_id.is_none() && self.list_client_order_id.is_none() {
+            anyhow::bail!("either order_list_id or list_client_order_id must be sent")
+        }
+        Ok(())
+    }
+}
+
+/// Smart Order Routing order, only LIMIT and MARKET are supported.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct SorNewOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub r#type: SpotOrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: f64,
+    pub price: Option<f64>,
+    pub new_client_order_id: Option<String>,
+    pub strategy_id: Option<i32>,
+    pub strategy_type: Option<i32>,
+    pub iceberg_qty: Option<f64>,
+    pub new_order_resp_type: Option<ResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(flatten)]
+    pub base: BaseRequest,
+}
+impl EndpointRequest for SorNewOrderRequest {
+    type Response = SorTransaction;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        match self.r#type {
+            SpotOrderType::Limit => {
+                if self.price.is_none() || self.time_in_force.is_none() {
+                    anyhow::bail!("LIMIT order requires price and time_in_force")
+                }
+            }
+            SpotOrderType::Market => {}
+            _ => anyhow::bail!("SOR only supports LIMIT and MARKET orders"),
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct SorOrderTestRequest {
+    pub compute_commission_rates: Option<bool>,
+    #[serde(flatten)]
+    pub order: SorNewOrderRequest,
+}
+impl EndpointRequest for SorOrderTestRequest {
+    type Response = OrderTestCommission;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        self.order.validate()
+    }
+}
+
 // impl SpotTrading {
 //     // Current open orders for ONE symbol
 //     pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
@@ -0,0 +1,590 @@
+//! Master account management of its sub-accounts. Every request names the
+//! sub-account by its email.
+
+use super::{BaseRequest, Endpoint, EndpointRequest, SecurityType};
+use crate::models::{string_or_float, string_or_float_opt};
+
+use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, APIEndPoint)]
+#[allow(dead_code)]
+pub enum SubAccountEP {
+    #[endpoint(GET, UserData, url = "/sapi/v1/sub-account/list")]
+    List,
+    #[endpoint(POST, UserData, url = "/sapi/v1/sub-account/virtualSubAccount")]
+    Create,
+    #[endpoint(GET, UserData, url = "/sapi/v4/sub-account/assets")]
+    Assets,
+    #[endpoint(GET, UserData, url = "/sapi/v1/sub-account/spotSummary")]
+    SpotSummary,
+    #[endpoint(GET, UserData, url = "/sapi/v2/sub-account/futures/accountSummary")]
+    FuturesSummary,
+    #[endpoint(POST, UserData, url = "/sapi/v1/sub-account/universalTransfer")]
+    UniversalTransfer,
+    #[endpoint(GET, UserData, url = "/sapi/v1/sub-account/universalTransfer")]
+    UniversalTransferHistory,
+    #[endpoint(POST, UserData, url = "/sapi/v1/sub-account/futures/enable")]
+    EnableFutures,
+    #[endpoint(POST, UserData, url = "/sapi/v1/sub-account/margin/enable")]
+    EnableMargin,
+    #[endpoint(POST, UserData, url = "/sapi/v1/sub-account/futures/transfer")]
+    FuturesTransfer,
+    #[endpoint(POST, UserData, url = "/sapi/v1/sub-account/margin/transfer")]
+    MarginTransfer,
+    #[endpoint(
+        GET,
+        UserData,
+        url = "/sapi/v1/sub-account/subAccountApi/ipRestriction"
+    )]
+    IpRestriction,
+    #[endpoint(
+        POST,
+        UserData,
+        url = "/sapi/v2/sub-account/subAccountApi/ipRestriction"
+    )]
+    UpdateIpRestriction,
+    #[endpoint(
+        DELETE,
+        UserData,
+        url = "/sapi/v1/sub-account/subAccountApi/ipRestriction/ipList"
+    )]
+    DeleteIpRestriction,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountListRequest {
+    pub email: Option<String>,
+    pub is_freeze: Option<bool>,
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for SubAccountListRequest {
+    type Response = SubAccountList;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountList {
+    pub sub_accounts: Vec<SubAccount>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccount {
+    pub email: String,
+    pub is_freeze: bool,
+    pub create_time: u64,
+    #[serde(default)]
+    pub is_managed_sub_account: bool,
+    #[serde(default)]
+    pub is_asset_management_sub_account: bool,
+}
+
+/// Creates a virtual sub-account; Binance derives its email from
+/// `sub_account_string`.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubAccountRequest {
+    pub sub_account_string: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for CreateSubAccountRequest {
+    type Response = SubAccountEmail;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountEmail {
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountAssetsRequest {
+    pub email: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for SubAccountAssetsRequest {
+    type Response = SubAccountAssets;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountAssets {
+    pub balances: Vec<SubAccountBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float_opt", default)]
+    pub freeze: Option<f64>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub withdrawing: Option<f64>,
+}
+
+/// BTC valuation of the spot wallet of one or all sub-accounts.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotSummaryRequest {
+    pub email: Option<String>,
+    pub page: Option<u32>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for SpotSummaryRequest {
+    type Response = SpotSummary;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotSummary {
+    pub total_count: u64,
+    #[serde(with = "string_or_float")]
+    pub master_account_total_asset: f64,
+    pub spot_sub_user_asset_btc_vo_list: Vec<SubAccountTotalAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTotalAsset {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_asset: f64,
+}
+
+/// Sent as `1` (USD-M) and `2` (COIN-M).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuturesType {
+    UsdM,
+    CoinM,
+}
+
+impl Serialize for FuturesType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(match self {
+            FuturesType::UsdM => 1,
+            FuturesType::CoinM => 2,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSummaryRequest {
+    pub futures_type: FuturesType,
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FuturesSummaryRequest {
+    type Response = FuturesSummary;
+}
+
+/// Only the summary matching the requested futures type is set.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSummary {
+    pub future_account_summary_resp: Option<UsdMFuturesSummary>,
+    pub delivery_account_summary_resp: Option<CoinMFuturesSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsdMFuturesSummary {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub sub_account_list: Vec<UsdMSubAccountSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsdMSubAccountSummary {
+    pub email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMFuturesSummary {
+    #[serde(rename = "totalMarginBalanceOfBTC", with = "string_or_float")]
+    pub total_margin_balance_of_btc: f64,
+    #[serde(rename = "totalUnrealizedProfitOfBTC", with = "string_or_float")]
+    pub total_unrealized_profit_of_btc: f64,
+    #[serde(rename = "totalWalletBalanceOfBTC", with = "string_or_float")]
+    pub total_wallet_balance_of_btc: f64,
+    pub asset: String,
+    pub sub_account_list: Vec<CoinMSubAccountSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMSubAccountSummary {
+    pub email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubAccountType {
+    Spot,
+    UsdtFuture,
+    CoinFuture,
+    Margin,
+    IsolatedMargin,
+}
+
+/// Transfers between the master and its sub-accounts, or between two
+/// sub-accounts. The master account is used for an omitted email.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferRequest {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub from_account_type: SubAccountType,
+    pub to_account_type: SubAccountType,
+    pub client_tran_id: Option<String>,
+    /// only for ISOLATED_MARGIN
+    pub symbol: Option<String>,
+    pub asset: String,
+    pub amount: f64,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for UniversalTransferRequest {
+    type Response = UniversalTransferResponse;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        let isolated = self.from_account_type == SubAccountType::IsolatedMargin
+            || self.to_account_type == SubAccountType::IsolatedMargin;
+        if isolated && self.symbol.is_none() {
+            anyhow::bail!("symbol is required for ISOLATED_MARGIN transfers");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferResponse {
+    pub tran_id: u64,
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferHistoryRequest {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub client_tran_id: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for UniversalTransferHistoryRequest {
+    type Response = UniversalTransferHistory;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.from_email.is_some() && self.to_email.is_some() {
+            anyhow::bail!("from_email and to_email can't be sent together");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferHistory {
+    pub result: Vec<SubAccountTransfer>,
+    pub total_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransfer {
+    pub tran_id: u64,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub create_time_stamp: u64,
+    pub from_account_type: SubAccountType,
+    pub to_account_type: SubAccountType,
+    pub status: String,
+    pub client_tran_id: Option<String>,
+}
+
+/// Used to enable futures on a sub-account.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableFuturesRequest {
+    pub email: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for EnableFuturesRequest {
+    type Response = FuturesEnabled;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesEnabled {
+    pub email: String,
+    pub is_futures_enabled: bool,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableMarginRequest {
+    pub email: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for EnableMarginRequest {
+    type Response = MarginEnabled;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginEnabled {
+    pub email: String,
+    pub is_margin_enabled: bool,
+}
+
+/// Direction of a transfer inside one sub-account, sent as `1` to `4`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuturesTransferType {
+    SpotToUsdMFutures,
+    UsdMFuturesToSpot,
+    SpotToCoinMFutures,
+    CoinMFuturesToSpot,
+}
+
+impl Serialize for FuturesTransferType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(match self {
+            FuturesTransferType::SpotToUsdMFutures => 1,
+            FuturesTransferType::UsdMFuturesToSpot => 2,
+            FuturesTransferType::SpotToCoinMFutures => 3,
+            FuturesTransferType::CoinMFuturesToSpot => 4,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesTransferRequest {
+    pub email: String,
+    pub asset: String,
+    pub amount: f64,
+    pub r#type: FuturesTransferType,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FuturesTransferRequest {
+    type Response = SubAccountTransferId;
+}
+
+/// Sent as `1` (spot to margin) and `2` (margin to spot).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarginTransferType {
+    SpotToMargin,
+    MarginToSpot,
+}
+
+impl Serialize for MarginTransferType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(match self {
+            MarginTransferType::SpotToMargin => 1,
+            MarginTransferType::MarginToSpot => 2,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransferRequest {
+    pub email: String,
+    pub asset: String,
+    pub amount: f64,
+    pub r#type: MarginTransferType,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for MarginTransferRequest {
+    type Response = SubAccountTransferId;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferId {
+    pub txn_id: String,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct IpRestrictionRequest {
+    pub email: String,
+    pub sub_account_api_key: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for IpRestrictionRequest {
+    type Response = IpRestriction;
+}
+
+/// Sent as `"1"` (any IP) and `"2"` (only the listed IPs).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpRestrictionStatus {
+    Unrestricted,
+    Restricted,
+}
+
+impl Serialize for IpRestrictionStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match self {
+            IpRestrictionStatus::Unrestricted => "1",
+            IpRestrictionStatus::Restricted => "2",
+        })
+    }
+}
+
+/// `ip_address` is a comma separated list of the IPs to add.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateIpRestrictionRequest {
+    pub email: String,
+    pub sub_account_api_key: String,
+    pub status: IpRestrictionStatus,
+    pub ip_address: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for UpdateIpRestrictionRequest {
+    type Response = IpRestriction;
+}
+
+/// `ip_address` is a comma separated list of the IPs to remove.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteIpRestrictionRequest {
+    pub email: String,
+    pub sub_account_api_key: String,
+    pub ip_address: String,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for DeleteIpRestrictionRequest {
+    type Response = IpRestriction;
+}
+
+/// The update endpoint returns `status` instead of `ipRestrict`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IpRestriction {
+    #[serde(alias = "status")]
+    pub ip_restrict: String,
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+    pub update_time: u64,
+    pub api_key: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_account_transfer() {
+        let mut request = UniversalTransferRequest::init(
+            SubAccountType::Spot,
+            SubAccountType::IsolatedMargin,
+            "USDT".into(),
+            50.0,
+            BaseRequest::init(),
+        );
+        request.to_email = Some("sub1@example.com".into());
+        assert!(request.validate().is_err());
+        request.symbol = Some("BTCUSDT".into());
+        assert!(request.validate().is_ok());
+        assert!(request.to_string().starts_with(
+            "toEmail=sub1%40example.com&fromAccountType=SPOT&toAccountType=ISOLATED_MARGIN&symbol=BTCUSDT&asset=USDT&amount=50&timestamp="
+        ));
+
+        let request = FuturesTransferRequest::init(
+            "sub1@example.com".into(),
+            "USDT".into(),
+            10.0,
+            FuturesTransferType::SpotToCoinMFutures,
+            BaseRequest::init(),
+        );
+        assert!(request.to_string().contains("&type=3&"));
+
+        let json = r#"{"status":"2","ipList":["69.210.67.14","8.34.21.10"],"updateTime":1636371437000,"apiKey":"k5V49ldtn4tszj6W3hystegdfvmGbqDzjmkCtpTvC0G74WhK7yd4rfCTo4lShf"}"#;
+        let restriction: IpRestriction = serde_json::from_str(json).unwrap();
+        assert_eq!(restriction.ip_restrict, "2");
+        assert_eq!(restriction.ip_list.len(), 2);
+    }
+}
@@ -1,18 +1,15 @@
-use crate::models::*;
-use crate::{
-    endpoints::{BaseRequest, Endpoint, SecurityType},
-    models::BnbBurnStatus,
-};
+use crate::endpoints::{Endpoint, SecurityType};
 use binance_api_macros::{APIEndPoint, APIRequestInit, APIRequestToString};
 
 use serde::{Serialize, Deserialize};
-use serde_qs::to_string;
 
 use super::{EndpointRequest, OneOrMany};
 
 pub enum SymbolType {
     Future
 }
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractType {
     Perpetual,
     CurrentMonth,
@@ -20,6 +17,22 @@ pub enum ContractType {
     CurrentQuarter,
     NextQuarter,
     PerpetualDelivering,
+    /// delisted symbols are reported with an empty contract type
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContractStatus {
+    PendingTrading,
+    Trading,
+    PreDelivering,
+    Delivering,
+    Delivered,
+    PreSettle,
+    Settling,
+    Close,
 }
 
 #[derive(Debug, APIEndPoint)]
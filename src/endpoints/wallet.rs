@@ -1,7 +1,11 @@
 #![allow(dead_code)]
 
-use crate::endpoints::{Endpoint, SecurityType};
-use crate::models::CoinInfo;
+use std::collections::HashMap;
+
+use crate::client::Client;
+use crate::endpoints::{spot_account::AccountRequest, Endpoint, SecurityType};
+use crate::errors::{BinanceApiError, Result};
+use crate::models::{string_or_float, string_or_float_opt, CoinInfo, TransactionId};
 
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +29,30 @@ pub enum WalletEP {
     DepositHisrec,
     #[endpoint(GET, UserData, url = "/sapi/v1/capital/withdraw/history")]
     WithdrawHistory,
+    #[endpoint(POST, UserData, url = "/sapi/v1/asset/transfer")]
+    UniversalTransfer,
+    #[endpoint(GET, UserData, url = "/sapi/v1/asset/transfer")]
+    UniversalTransferHistory,
+    #[endpoint(POST, UserData, url = "/sapi/v1/asset/get-funding-asset")]
+    FundingAsset,
+    #[endpoint(POST, UserData, url = "/sapi/v3/asset/getUserAsset")]
+    UserAsset,
+    #[endpoint(GET, UserData, url = "/sapi/v1/asset/tradeFee")]
+    TradeFee,
+    #[endpoint(POST, UserData, url = "/sapi/v1/asset/dust-btc")]
+    DustBtc,
+    #[endpoint(POST, UserData, url = "/sapi/v1/asset/dust")]
+    Dust,
+    #[endpoint(GET, UserData, url = "/sapi/v1/asset/dribblet")]
+    Dribblet,
+    #[endpoint(GET, UserData, url = "/sapi/v1/account/apiRestrictions")]
+    ApiRestrictions,
+    #[endpoint(GET, UserData, url = "/sapi/v1/account/status")]
+    AccountStatus,
+    #[endpoint(GET, UserData, url = "/sapi/v1/account/apiTradingStatus")]
+    ApiTradingStatus,
+    #[endpoint(GET, UserData, url = "/sapi/v1/accountSnapshot")]
+    AccountSnapshot,
 }
 
 #[derive(Debug, Serialize, APIRequestToString)]
@@ -69,7 +97,53 @@ impl EndpointRequest for AllCoinsRequest {
 //     }
 // }
 
-#[derive(Debug, Serialize, Deserialize, APIRequestInit, APIRequestToString)]
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDetailRequest {
+    pub asset: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for AssetDetailRequest {
+    type Response = HashMap<String, AssetDetail>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDetail {
+    #[serde(with = "string_or_float")]
+    pub min_withdraw_amount: f64,
+    pub deposit_status: bool,
+    #[serde(with = "string_or_float")]
+    pub withdraw_fee: f64,
+    pub withdraw_status: bool,
+    pub deposit_tip: Option<String>,
+}
+
+/// The default network of the coin is used when `network` is omitted.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddressRequest {
+    pub coin: String,
+    pub network: Option<String>,
+    pub amount: Option<f64>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for DepositAddressRequest {
+    type Response = DepositAddress;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddress {
+    pub address: String,
+    pub coin: String,
+    pub tag: String,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawRequest {
     pub coin: String,
@@ -81,8 +155,8 @@ pub struct WithdrawRequest {
     pub transaction_fee_flag: Option<bool>,
     pub name: Option<String>,
     pub wallet_type: Option<i32>,
-    pub recv_window: Option<u64>,
-    pub timestamp: u64,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -95,7 +169,7 @@ impl EndpointRequest for WithdrawRequest {
     type Response = WithdrawResponse;
 }
 
-#[derive(Debug, Serialize, Deserialize, APIRequestInit, APIRequestToString)]
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
 #[serde(rename_all = "camelCase")]
 pub struct DepositHisrecRequest {
     pub include_source: Option<bool>,
@@ -105,9 +179,9 @@ pub struct DepositHisrecRequest {
     pub end_time: Option<i64>,
     pub offset: Option<i64>,
     pub limit: Option<i64>,
-    pub recv_window: Option<i64>,
-    pub timestamp: u64,
     pub tx_id: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -132,7 +206,7 @@ impl EndpointRequest for DepositHisrecRequest {
     type Response = Vec<DepositHisrecResponse>;
 }
 
-#[derive(Debug, Serialize, Deserialize, APIRequestInit, APIRequestToString)]
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawHistoryRequest {
     pub coin: Option<String>,
@@ -142,8 +216,8 @@ pub struct WithdrawHistoryRequest {
     pub limit: Option<i64>,
     pub start_time: Option<i64>,
     pub end_time: Option<i64>,
-    pub recv_window: Option<i64>,
-    pub timestamp: u64,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -179,3 +253,693 @@ impl WithdrawHistoryResponse {
 impl EndpointRequest for WithdrawHistoryRequest {
     type Response = Vec<WithdrawHistoryResponse>;
 }
+
+/// Source and destination wallets of a universal transfer, `FROM_TO`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UniversalTransferType {
+    MainUmfuture,
+    MainCmfuture,
+    MainMargin,
+    UmfutureMain,
+    UmfutureMargin,
+    CmfutureMain,
+    CmfutureMargin,
+    MarginMain,
+    MarginUmfuture,
+    MarginCmfuture,
+    IsolatedmarginMargin,
+    MarginIsolatedmargin,
+    IsolatedmarginIsolatedmargin,
+    MainFunding,
+    FundingMain,
+    FundingUmfuture,
+    UmfutureFunding,
+    MarginFunding,
+    FundingMargin,
+    FundingCmfuture,
+    CmfutureFunding,
+    MainOption,
+    OptionMain,
+    UmfutureOption,
+    OptionUmfuture,
+    MarginOption,
+    OptionMargin,
+    FundingOption,
+    OptionFunding,
+    MainPortfolioMargin,
+    PortfolioMarginMain,
+}
+
+impl UniversalTransferType {
+    fn is_from_isolated(self) -> bool {
+        matches!(
+            self,
+            UniversalTransferType::IsolatedmarginMargin
+                | UniversalTransferType::IsolatedmarginIsolatedmargin
+        )
+    }
+
+    fn is_to_isolated(self) -> bool {
+        matches!(
+            self,
+            UniversalTransferType::MarginIsolatedmargin
+                | UniversalTransferType::IsolatedmarginIsolatedmargin
+        )
+    }
+}
+
+/// `from_symbol`/`to_symbol` name the isolated margin pair on the
+/// isolated side of the transfer.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferRequest {
+    pub r#type: UniversalTransferType,
+    pub asset: String,
+    pub amount: f64,
+    pub from_symbol: Option<String>,
+    pub to_symbol: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for UniversalTransferRequest {
+    type Response = TransactionId;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.r#type.is_from_isolated() && self.from_symbol.is_none() {
+            anyhow::bail!("from_symbol is required when transferring from isolated margin");
+        }
+        if self.r#type.is_to_isolated() && self.to_symbol.is_none() {
+            anyhow::bail!("to_symbol is required when transferring to isolated margin");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferHistoryRequest {
+    pub r#type: UniversalTransferType,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub current: Option<u32>,
+    pub size: Option<u32>,
+    pub from_symbol: Option<String>,
+    pub to_symbol: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for UniversalTransferHistoryRequest {
+    type Response = UniversalTransferHistory;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferHistory {
+    pub total: u64,
+    #[serde(default)]
+    pub rows: Vec<UniversalTransfer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransfer {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub r#type: UniversalTransferType,
+    pub status: String,
+    pub tran_id: u64,
+    pub timestamp: u64,
+}
+
+/// Funding wallet balances; all non-zero assets when `asset` is omitted.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingAssetRequest {
+    pub asset: Option<String>,
+    pub need_btc_valuation: Option<bool>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for FundingAssetRequest {
+    type Response = Vec<WalletAsset>;
+}
+
+/// Spot wallet balances; all non-zero assets when `asset` is omitted.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAssetRequest {
+    pub asset: Option<String>,
+    pub need_btc_valuation: Option<bool>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for UserAssetRequest {
+    type Response = Vec<WalletAsset>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub freeze: f64,
+    #[serde(with = "string_or_float")]
+    pub withdrawing: f64,
+    #[serde(with = "string_or_float_opt", default)]
+    pub ipoable: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub btc_valuation: f64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFeeRequest {
+    pub symbol: Option<String>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for TradeFeeRequest {
+    type Response = Vec<TradeFee>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub maker_commission: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_commission: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DustAccountType {
+    Spot,
+    Margin,
+}
+
+/// Lists the assets that can be converted to BNB.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct DustBtcRequest {
+    pub account_type: Option<DustAccountType>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for DustBtcRequest {
+    type Response = DustAssets;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAssets {
+    #[serde(default)]
+    pub details: Vec<DustAsset>,
+    #[serde(rename = "totalTransferBtc", with = "string_or_float")]
+    pub total_transfer_btc: f64,
+    #[serde(rename = "totalTransferBNB", with = "string_or_float")]
+    pub total_transfer_bnb: f64,
+    #[serde(with = "string_or_float")]
+    pub dribblet_percentage: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAsset {
+    pub asset: String,
+    pub asset_full_name: String,
+    #[serde(with = "string_or_float")]
+    pub amount_free: f64,
+    #[serde(rename = "toBTC", with = "string_or_float")]
+    pub to_btc: f64,
+    #[serde(rename = "toBNB", with = "string_or_float")]
+    pub to_bnb: f64,
+    #[serde(rename = "toBNBOffExchange", with = "string_or_float")]
+    pub to_bnb_off_exchange: f64,
+    #[serde(with = "string_or_float")]
+    pub exchange: f64,
+}
+
+/// Converts every asset in `assets` to BNB in a single call. The endpoint
+/// takes the asset list as a repeated `asset` parameter, which serde_qs can't
+/// produce, hence the hand written `Display`.
+#[derive(Debug, Serialize, APIRequestInit)]
+#[serde(rename_all = "camelCase")]
+pub struct DustRequest {
+    #[serde(skip)]
+    pub assets: Vec<String>,
+    pub account_type: Option<DustAccountType>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl std::fmt::Display for DustRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for asset in self.assets.iter() {
+            let asset: String = url::form_urlencoded::byte_serialize(asset.as_bytes()).collect();
+            write!(f, "asset={asset}&")?;
+        }
+        f.write_str(&serde_qs::to_string(self).unwrap())
+    }
+}
+impl EndpointRequest for DustRequest {
+    type Response = DustTransferResult;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.assets.is_empty() {
+            anyhow::bail!("at least one asset must be converted");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransferResult {
+    #[serde(with = "string_or_float")]
+    pub total_service_charge: f64,
+    #[serde(with = "string_or_float")]
+    pub total_transfered: f64,
+    pub transfer_result: Vec<DustTransfer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransfer {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub from_asset: String,
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    pub tran_id: u64,
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+}
+
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct DribbletRequest {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for DribbletRequest {
+    type Response = DribbletHistory;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DribbletHistory {
+    pub total: u64,
+    #[serde(default)]
+    pub user_asset_dribblets: Vec<Dribblet>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Dribblet {
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub total_transfered_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub total_service_charge_amount: f64,
+    pub trans_id: u64,
+    pub user_asset_dribblet_details: Vec<DribbletDetail>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DribbletDetail {
+    pub trans_id: u64,
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+    pub from_asset: String,
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct ApiRestrictionsRequest(pub BaseRequest);
+impl EndpointRequest for ApiRestrictionsRequest {
+    type Response = ApiRestrictions;
+}
+
+/// Permissions of the API key.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiRestrictions {
+    pub ip_restrict: bool,
+    pub create_time: u64,
+    pub enable_reading: bool,
+    pub enable_spot_and_margin_trading: bool,
+    pub enable_withdrawals: bool,
+    pub enable_internal_transfer: bool,
+    pub enable_margin: bool,
+    pub enable_futures: bool,
+    pub permits_universal_transfer: bool,
+    #[serde(default)]
+    pub enable_vanilla_options: bool,
+    #[serde(default)]
+    pub enable_portfolio_margin_trading: bool,
+    /// only set for keys with time-limited trading
+    pub trading_authority_expiration_time: Option<u64>,
+}
+
+impl ApiRestrictions {
+    /// Fails with `PermissionDenied` if the key can't call the endpoint.
+    /// Only Trade/Margin endpoints and withdrawals are checked.
+    pub fn check(&self, security: &SecurityType, path: &str) -> Result<()> {
+        let required = if path == "/sapi/v1/capital/withdraw/apply" {
+            Some(("enableWithdrawals", self.enable_withdrawals))
+        } else {
+            match security {
+                SecurityType::Trade if path.starts_with("/fapi") || path.starts_with("/dapi") => {
+                    Some(("enableFutures", self.enable_futures))
+                }
+                SecurityType::Trade if path.starts_with("/eapi") => {
+                    Some(("enableVanillaOptions", self.enable_vanilla_options))
+                }
+                SecurityType::Trade if path.starts_with("/papi") => Some((
+                    "enablePortfolioMarginTrading",
+                    self.enable_portfolio_margin_trading,
+                )),
+                SecurityType::Trade => Some((
+                    "enableSpotAndMarginTrading",
+                    self.enable_spot_and_margin_trading,
+                )),
+                // orders only need trading, loans and transfers need margin
+                SecurityType::Margin if path.starts_with("/sapi/v1/margin/order") => Some((
+                    "enableSpotAndMarginTrading",
+                    self.enable_spot_and_margin_trading,
+                )),
+                SecurityType::Margin => Some(("enableMargin", self.enable_margin)),
+                _ => None,
+            }
+        };
+        match required {
+            Some((permission, false)) => Err(BinanceApiError::PermissionDenied(
+                path.to_string(),
+                permission,
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct AccountStatusRequest(pub BaseRequest);
+impl EndpointRequest for AccountStatusRequest {
+    type Response = AccountStatus;
+}
+
+/// `data` is "Normal" unless the account is restricted.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountStatus {
+    pub data: String,
+}
+
+#[derive(Debug, Serialize, APIRequestToString)]
+pub struct ApiTradingStatusRequest(pub BaseRequest);
+impl EndpointRequest for ApiTradingStatusRequest {
+    type Response = AccountApiTradingStatus;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountApiTradingStatus {
+    pub data: ApiTradingStatusData,
+}
+
+/// Spot trading lock triggered by the quantitative rules.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatusData {
+    pub is_locked: bool,
+    /// 0 when not locked
+    pub planned_recover_time: u64,
+    pub trigger_condition: HashMap<String, u64>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SnapshotType {
+    Spot,
+    Margin,
+    Futures,
+}
+
+/// Daily snapshots, `limit` between 7 and 30 days.
+#[derive(Debug, Serialize, APIRequestInit, APIRequestToString)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSnapshotRequest {
+    pub r#type: SnapshotType,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u8>,
+    #[serde(flatten)]
+    pub base_request: BaseRequest,
+}
+impl EndpointRequest for AccountSnapshotRequest {
+    type Response = AccountSnapshots;
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.limit.is_some_and(|l| !(7..=30).contains(&l)) {
+            anyhow::bail!("limit must be between 7 and 30");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSnapshots {
+    #[serde(default)]
+    pub snapshot_vos: Vec<AccountSnapshot>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSnapshot {
+    #[serde(flatten)]
+    pub data: SnapshotData,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", content = "data", rename_all = "lowercase")]
+pub enum SnapshotData {
+    Spot(SpotSnapshot),
+    Margin(MarginSnapshot),
+    Futures(FuturesSnapshot),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotSnapshot {
+    pub balances: Vec<crate::models::Balance>,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginSnapshot {
+    #[serde(with = "string_or_float")]
+    pub margin_level: f64,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+    pub user_assets: Vec<crate::models::MarginAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSnapshot {
+    pub assets: Vec<FuturesSnapshotAsset>,
+    pub position: Vec<FuturesSnapshotPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSnapshotAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSnapshotPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub position_amt: f64,
+    #[serde(with = "string_or_float")]
+    pub un_realized_profit: f64,
+}
+
+/// Picks the spot balances worth less than `max_btc_value` that Binance
+/// accepts as dust.
+fn select_dust(
+    balances: &[crate::models::Balance],
+    convertible: &[DustAsset],
+    max_btc_value: f64,
+) -> Vec<String> {
+    balances
+        .iter()
+        .filter(|b| {
+            b.free
+                .parse::<f64>()
+                .map(|free| free > 0.0)
+                .unwrap_or(false)
+        })
+        .filter(|b| {
+            convertible
+                .iter()
+                .any(|d| d.asset == b.asset && d.to_btc < max_btc_value)
+        })
+        .map(|b| b.asset.clone())
+        .collect()
+}
+
+/// Converts every spot balance worth less than `max_btc_value` BTC to BNB.
+/// The candidates are taken from the account balances, valued with
+/// `dust-btc` and converted with a single `dust` call. Returns `None` when
+/// there is nothing to sweep.
+pub async fn sweep_dust(client: &Client, max_btc_value: f64) -> Result<Option<DustTransferResult>> {
+    let account = client
+        .access::<AccountRequest>(
+            &super::spot_account::SpotAccountEP::Account,
+            Some(AccountRequest::init(BaseRequest::init())),
+        )
+        .await?;
+    let convertible = client
+        .access::<DustBtcRequest>(
+            &WalletEP::DustBtc,
+            Some(DustBtcRequest::init(BaseRequest::init())),
+        )
+        .await?;
+
+    let assets = select_dust(&account.balances, &convertible.details, max_btc_value);
+    if assets.is_empty() {
+        return Ok(None);
+    }
+    let result = client
+        .access::<DustRequest>(
+            &WalletEP::Dust,
+            Some(DustRequest::init(assets, BaseRequest::init())),
+        )
+        .await?;
+    Ok(Some(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_universal_transfer() {
+        let mut request = UniversalTransferRequest::init(
+            UniversalTransferType::MarginIsolatedmargin,
+            "USDT".into(),
+            10.0,
+            BaseRequest::init(),
+        );
+        assert!(request.validate().is_err());
+        request.to_symbol = Some("BTCUSDT".into());
+        assert!(request.validate().is_ok());
+        assert!(request.to_string().starts_with(
+            "type=MARGIN_ISOLATEDMARGIN&asset=USDT&amount=10&toSymbol=BTCUSDT&timestamp="
+        ));
+
+        let json = r#"{"total":2,"rows":[{"asset":"USDT","amount":"1","type":"MAIN_UMFUTURE","status":"CONFIRMED","tranId":11415955596,"timestamp":1544433328000}]}"#;
+        let history: UniversalTransferHistory = serde_json::from_str(json).unwrap();
+        assert_eq!(history.rows[0].r#type, UniversalTransferType::MainUmfuture);
+        let history: UniversalTransferHistory = serde_json::from_str(r#"{"total":0}"#).unwrap();
+        assert!(history.rows.is_empty());
+    }
+
+    #[test]
+    fn test_dust() {
+        let mut request = DustRequest::init(vec!["ETH".into(), "LTC".into()], BaseRequest::init());
+        request.account_type = Some(DustAccountType::Spot);
+        assert!(request
+            .to_string()
+            .starts_with("asset=ETH&asset=LTC&accountType=SPOT&timestamp="));
+
+        let json = r#"{"details":[{"asset":"ADA","assetFullName":"ADA","amountFree":"6.21","toBTC":"0.00016848","toBNB":"0.01777302","toBNBOffExchange":"0.01741756","exchange":"0.00035546"},{"asset":"ETH","assetFullName":"Ethereum","amountFree":"0.001","toBTC":"0.004","toBNB":"0.1","toBNBOffExchange":"0.09","exchange":"0.01"}],"totalTransferBtc":"0.00416848","totalTransferBNB":"0.11777302","dribbletPercentage":"0.02"}"#;
+        let dust: DustAssets = serde_json::from_str(json).unwrap();
+        let balance = |asset: &str, free: &str| crate::models::Balance {
+            asset: asset.into(),
+            free: free.into(),
+            locked: "0".into(),
+        };
+        let balances = vec![
+            balance("ADA", "6.21"),
+            balance("ETH", "0.001"),
+            balance("BTC", "0.00001"),
+            balance("XRP", "0"),
+        ];
+        assert_eq!(
+            select_dust(&balances, &dust.details, 0.001),
+            vec!["ADA".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_api_restrictions() {
+        let json = r#"{"ipRestrict":false,"createTime":1698645219000,"enableReading":true,"enableWithdrawals":false,"enableInternalTransfer":true,"enableMargin":false,"enableFutures":true,"permitsUniversalTransfer":true,"enableVanillaOptions":false,"enableFixApiTrade":false,"enableFixReadOnly":true,"enableSpotAndMarginTrading":true,"enablePortfolioMarginTrading":false}"#;
+        let restrictions: ApiRestrictions = serde_json::from_str(json).unwrap();
+        assert!(restrictions
+            .check(&SecurityType::Trade, "/api/v3/order")
+            .is_ok());
+        assert!(restrictions
+            .check(&SecurityType::Trade, "/fapi/v1/order")
+            .is_ok());
+        assert!(restrictions
+            .check(&SecurityType::Margin, "/sapi/v1/margin/order")
+            .is_ok());
+        assert!(matches!(
+            restrictions.check(&SecurityType::Margin, "/sapi/v1/margin/borrow-repay"),
+            Err(BinanceApiError::PermissionDenied(_, "enableMargin"))
+        ));
+        assert!(matches!(
+            restrictions.check(&SecurityType::Trade, "/eapi/v1/order"),
+            Err(BinanceApiError::PermissionDenied(_, "enableVanillaOptions"))
+        ));
+        assert!(restrictions
+            .check(&SecurityType::UserData, "/sapi/v1/capital/withdraw/apply")
+            .is_err());
+        assert!(restrictions
+            .check(&SecurityType::UserData, "/sapi/v1/capital/withdraw/history")
+            .is_ok());
+
+        let json = r#"{"code":200,"msg":"","snapshotVos":[{"data":{"balances":[{"asset":"BTC","free":"0.09905021","locked":"0.00000000"}],"totalAssetOfBtc":"0.09942700"},"type":"spot","updateTime":1576281599000},{"data":{"assets":[{"asset":"USDT","marginBalance":"118.99782335","walletBalance":"120.23811389"}],"position":[{"entryPrice":"7130.41000000","markPrice":"7257.66239673","positionAmt":"0.01000000","symbol":"BTCUSDT","unRealizedProfit":"1.24029054"}]},"type":"futures","updateTime":1576281599000}]}"#;
+        let snapshots: AccountSnapshots = serde_json::from_str(json).unwrap();
+        assert!(
+            matches!(&snapshots.snapshot_vos[0].data, SnapshotData::Spot(s) if s.balances.len() == 1)
+        );
+        assert!(
+            matches!(&snapshots.snapshot_vos[1].data, SnapshotData::Futures(f) if f.position[0].symbol == "BTCUSDT")
+        );
+    }
+}
@@ -13,7 +13,7 @@ pub enum BinanceApiError {
     JSONValueSerDeError(#[from] serde_json::Error),
 
     #[error("Websocket handshake: {0}")]
-    WsHandShake(#[from] WsErr),
+    WsHandShake(Box<WsErr>),
 
     #[error("Request error: {0}")]
     RequestError(#[from] RWError),
@@ -27,6 +27,18 @@ pub enum BinanceApiError {
     #[error("Asset not found: {0}")]
     AssetNotFound(String),
 
+    #[error("Invalid option symbol: {0}")]
+    InvalidOptionSymbol(String),
+
+    #[error("API key is not allowed to call {0}: {1} is disabled")]
+    PermissionDenied(String, &'static str),
+
+    #[error("Failed after redeeming {redeemed}: {source}")]
+    PartialRedemption {
+        redeemed: f64,
+        source: Box<BinanceApiError>,
+    },
+
     #[error("System maintenance")]
     WalletMaintenance,
 
@@ -42,3 +54,9 @@ pub enum BinanceApiError {
     #[error("Custom error: {0}")]
     Custom(String),
 }
+
+impl From<WsErr> for BinanceApiError {
+    fn from(e: WsErr) -> Self {
+        Self::WsHandShake(Box::new(e))
+    }
+}
@@ -19,15 +19,14 @@ pub use endpoints::SecurityType;
 
 #[cfg(test)]
 mod tests {
-    use super::{MAINNET, TESTNET};
+    use super::MAINNET;
 
     use crate::{
         client,
         endpoints::{
-            convert, margin, spot_account, spot_market, usd_m_futures, wallet, BaseRequest, OneOrManySymbol
+            coin_m_futures, convert, futures_market, margin, spot_account, spot_market, usd_m_futures, wallet, BaseRequest, OneOrManySymbol
         },
         models::*,
-        ws_streams::market_streams::{MarketStreams, PartialBookDepthLevel},
     };
 
     #[tokio::test]
@@ -69,6 +68,25 @@ mod tests {
         println!("{:?}", resp);
     }
 
+    #[tokio::test]
+    async fn market_data_ticker_24hr() {
+        let client = client::Client::new(None, None, &MAINNET.rest_api_endpoint);
+        let mut req = spot_market::Ticker24hRequest::init();
+        req.symbols = Some(OneOrManySymbol::Many(vec![
+            "ETHUSDT".to_string(),
+            "BNBUSDT".to_string(),
+        ]));
+        req.r#type = Some(spot_market::TickerType::MINI);
+        let resp = client
+            .access::<spot_market::Ticker24hRequest>(
+                &spot_market::SpotMarketEP::Ticker24hr,
+                Some(req),
+            )
+            .await
+            .unwrap();
+        println!("{:?}", resp);
+    }
+
     #[tokio::test]
     async fn market_data_klines() {
         let client = client::Client::new(None, None, &MAINNET.rest_api_endpoint);
@@ -76,14 +94,10 @@ mod tests {
             "BTCUSDT".to_string(),
             spot_market::KlineInterval::_1h,
         );
-        let resp = client
+        let resp: Vec<KlineSummary> = client
             .access::<spot_market::KlinesRequest>(&spot_market::SpotMarketEP::Klines, Some(req))
             .await
             .unwrap();
-        let resp: Vec<KlineSummary> = resp
-            .into_iter()
-            .map(|v| KlineSummary::try_from(v).unwrap())
-            .collect();
         println!("{:?}", resp);
     }
 
@@ -135,6 +149,22 @@ mod tests {
         println!("{:?}", resp);
     }
 
+    #[tokio::test]
+    async fn spot_account_commission() {
+        let apikey = envmnt::get_or_panic("TEST_APIKEY");
+        let secret = envmnt::get_or_panic("TEST_SECRET");
+        let client = client::Client::new(Some(apikey), Some(secret), &MAINNET.rest_api_endpoint);
+        let req = spot_account::CommissionRequest::init("BTCUSDT".to_string(), BaseRequest::init());
+        let resp = client
+            .access::<spot_account::CommissionRequest>(
+                &spot_account::SpotAccountEP::Commission,
+                Some(req),
+            )
+            .await
+            .unwrap();
+        println!("{:?}", resp);
+    }
+
     #[tokio::test]
     async fn wallet_system_status() {
         let apikey = envmnt::get_or_panic("TEST_APIKEY");
@@ -219,4 +249,34 @@ mod tests {
             .unwrap();
         println!("{:?}", resp);
     }
+
+    #[tokio::test]
+    async fn usd_m_futures_premium_index() {
+        let client = client::Client::new(None, None, &MAINNET.futures_rest_api_endpoint);
+        let mut req = futures_market::PremiumIndexRequest::init();
+        req.symbol.replace("BTCUSDT".to_string());
+        let resp = client
+            .access::<futures_market::PremiumIndexRequest>(
+                &futures_market::FuturesMarketEP::PremiumIndex,
+                Some(req),
+            )
+            .await
+            .unwrap();
+        println!("{:?}", resp);
+    }
+
+    #[tokio::test]
+    async fn coin_m_futures_premium_index() {
+        let client = client::Client::new(None, None, &MAINNET.coin_m_rest_api_endpoint);
+        let mut req = coin_m_futures::PremiumIndexRequest::init();
+        req.pair.replace("BTCUSD".to_string());
+        let resp = client
+            .access::<coin_m_futures::PremiumIndexRequest>(
+                &coin_m_futures::COIN_M_FutureEP::PremiumIndex,
+                Some(req),
+            )
+            .await
+            .unwrap();
+        println!("{:?}", resp);
+    }
 }
@@ -17,12 +17,66 @@ pub struct PartialOrderBookDepthEvent {
     pub asks: Vec<PartialOrderBookDepthAsk>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AccountAndSymbolPermission {
     Spot,
     Margin,
     Leveraged,
+    /// `TRD_GRP_002`, `TRD_GRP_003`, ...
+    TrdGrp(u16),
+    /// permissions added by Binance after this crate
+    Other(String),
+}
+
+impl Serialize for AccountAndSymbolPermission {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            AccountAndSymbolPermission::Spot => serializer.serialize_str("SPOT"),
+            AccountAndSymbolPermission::Margin => serializer.serialize_str("MARGIN"),
+            AccountAndSymbolPermission::Leveraged => serializer.serialize_str("LEVERAGED"),
+            AccountAndSymbolPermission::TrdGrp(n) => {
+                serializer.serialize_str(&format!("TRD_GRP_{:03}", n))
+            }
+            AccountAndSymbolPermission::Other(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountAndSymbolPermission {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "SPOT" => Ok(AccountAndSymbolPermission::Spot),
+            "MARGIN" => Ok(AccountAndSymbolPermission::Margin),
+            "LEVERAGED" => Ok(AccountAndSymbolPermission::Leveraged),
+            _ => Ok(s
+                .strip_prefix("TRD_GRP_")
+                .and_then(|n| n.parse().ok())
+                .map(AccountAndSymbolPermission::TrdGrp)
+                .unwrap_or(AccountAndSymbolPermission::Other(s))),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SymbolStatus {
+    PreTrading,
+    Trading,
+    PostTrading,
+    EndOfDay,
+    Halt,
+    AuctionMatch,
+    Break,
+    /// statuses added by Binance after this crate
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -48,6 +102,16 @@ pub struct ExchangeInformation {
     pub server_time: u64,
     pub rate_limits: Vec<RateLimit>,
     pub symbols: Vec<Symbol>,
+    /// only present when Smart Order Routing is enabled for some symbols
+    #[serde(default)]
+    pub sors: Vec<Sor>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Sor {
+    pub base_asset: String,
+    pub symbols: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -63,7 +127,7 @@ pub struct RateLimit {
 #[serde(rename_all = "camelCase")]
 pub struct Symbol {
     pub symbol: String,
-    pub status: String,
+    pub status: SymbolStatus,
     pub base_asset: String,
     pub base_asset_precision: u64,
     pub quote_asset: String,
@@ -73,6 +137,10 @@ pub struct Symbol {
     pub is_spot_trading_allowed: bool,
     pub is_margin_trading_allowed: bool,
     pub filters: Vec<Filters>,
+    /// the symbol can be traded by accounts holding every permission of any
+    /// one of the sets
+    #[serde(default)]
+    pub permission_sets: Vec<Vec<AccountAndSymbolPermission>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -169,6 +237,56 @@ pub struct AccountInformation {
     pub balances: Vec<Balance>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRates {
+    #[serde(with = "string_or_float")]
+    pub maker: f64,
+    #[serde(with = "string_or_float")]
+    pub taker: f64,
+    #[serde(with = "string_or_float")]
+    pub buyer: f64,
+    #[serde(with = "string_or_float")]
+    pub seller: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCommission {
+    pub symbol: String,
+    pub standard_commission: CommissionRates,
+    pub tax_commission: CommissionRates,
+    pub discount: CommissionDiscount,
+}
+
+/// Current order count usage of the account for every ORDERS rate limit interval.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfilledOrderCount {
+    pub rate_limit_type: String,
+    pub interval: String,
+    pub interval_num: u16,
+    pub limit: u64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PreventedMatch {
+    pub symbol: String,
+    pub prevented_match_id: u64,
+    pub taker_order_id: u64,
+    pub maker_symbol: String,
+    pub maker_order_id: u64,
+    pub trade_group_id: u64,
+    pub self_trade_prevention_mode: crate::endpoints::spot_trading::SelfTradePreventionMode,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub maker_prevented_quantity: f64,
+    pub transact_time: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Balance {
@@ -178,7 +296,7 @@ pub struct Balance {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[serde(rename_all = "UPPERCASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderStatus {
     New,
     PendingNew,
@@ -217,6 +335,85 @@ pub struct Order {
     pub orig_quote_order_qty: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ContingencyType {
+    Oco,
+    Oto,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListStatusType {
+    Response,
+    ExecStarted,
+    Updated,
+    AllDone,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ListOrderStatus {
+    Executing,
+    AllDone,
+    Reject,
+}
+
+/// An OCO, OTO or OTOCO order list.
+///
+/// `order_reports` is only returned when placing or canceling a list,
+/// the query endpoints leave it out.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: i64,
+    pub contingency_type: ContingencyType,
+    pub list_status_type: ListStatusType,
+    pub list_order_status: ListOrderStatus,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+    #[serde(default)]
+    pub order_reports: Vec<OrderListReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListReport {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    pub orig_client_order_id: Option<String>,
+    pub transact_time: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    pub status: OrderStatus,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub stop_price: f64,
+    pub working_time: Option<i64>,
+    pub self_trade_prevention_mode: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderCanceled {
@@ -225,6 +422,7 @@ pub struct OrderCanceled {
     pub order_id: Option<u64>,
     pub client_order_id: Option<String>,
 }
+#[deprecated(note = "use wallet::UniversalTransferType with the universal transfer endpoint")]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum SpotFuturesTransferType {
@@ -282,6 +480,124 @@ pub struct FillInfo {
     pub commission_asset: String,
     pub trade_id: Option<u64>,
 }
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WorkingFloor {
+    Exchange,
+    Sor,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AllocationType {
+    Sor,
+}
+
+/// Response to a Smart Order Routing order (endpoint /api/v3/sor/order).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SorTransaction {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    pub status: OrderStatus,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    pub working_time: Option<i64>,
+    #[serde(default)]
+    pub fills: Vec<SorFillInfo>,
+    pub working_floor: Option<WorkingFloor>,
+    pub self_trade_prevention_mode: Option<String>,
+    #[serde(default)]
+    pub used_sor: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SorFillInfo {
+    pub match_type: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub trade_id: i64,
+    pub alloc_id: u64,
+}
+
+/// A trade resulting from an order placed through SOR (endpoint /api/v3/myAllocations).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Allocation {
+    pub symbol: String,
+    pub allocation_id: u64,
+    pub allocation_type: AllocationType,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_allocator: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCommissionRates {
+    #[serde(with = "string_or_float")]
+    pub maker: f64,
+    #[serde(with = "string_or_float")]
+    pub taker: f64,
+    /// only returned by the account commission endpoint
+    #[serde(with = "string_or_float_opt", default)]
+    pub buyer: Option<f64>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub seller: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionDiscount {
+    pub enabled_for_account: bool,
+    pub enabled_for_symbol: bool,
+    pub discount_asset: String,
+    #[serde(with = "string_or_float")]
+    pub discount: f64,
+}
+
+/// Response to a test order sent with `computeCommissionRates=true`.
+///
+/// All fields are missing when the commission rates were not requested.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderTestCommission {
+    pub standard_commission_for_order: Option<OrderCommissionRates>,
+    pub tax_commission_for_order: Option<OrderCommissionRates>,
+    pub discount: Option<CommissionDiscount>,
+}
+
 /// Response to a test order (endpoint /api/v3/order/test).
 ///
 /// Currently, the API responds {} on a successfull test transaction,
@@ -356,10 +672,13 @@ pub struct SymbolPrice {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
     pub price: f64,
+    /// last trade time
+    pub close_time: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -442,6 +761,48 @@ pub struct PriceStatsFull {
     pub count: u64,
 }
 
+/// 24hr ticker statistics, FULL or MINI depending on the requested type.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum PriceStats {
+    Full(PriceStatsFull),
+    Mini(PriceStatsMini),
+}
+
+/// Rolling window and trading day ticker statistics, FULL or MINI depending on the requested type.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum WindowPriceStats {
+    Full(WindowPriceStatsFull),
+    Mini(PriceStatsMini),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowPriceStatsFull {
+    pub symbol: String,
+    pub price_change: String,
+    pub price_change_percent: String,
+    pub weighted_avg_price: String,
+    #[serde(with = "string_or_float")]
+    pub open_price: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_volume: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceStatsMini {
@@ -477,7 +838,8 @@ pub struct AggTrade {
     pub last_id: u64,
     #[serde(rename = "m")]
     pub maker: bool,
-    #[serde(rename = "M")]
+    /// not sent for futures aggregate trades
+    #[serde(rename = "M", default)]
     pub best_match: bool,
     #[serde(rename = "p", with = "string_or_float")]
     pub price: f64,
@@ -637,26 +999,401 @@ pub struct OrderTradeEvent {
     #[serde(rename = "n")]
     pub commission: String,
 
-    #[serde(skip, rename = "N")]
-    pub asset_commisioned: Option<String>,
+    #[serde(skip, rename = "N")]
+    pub asset_commisioned: Option<String>,
+
+    #[serde(rename = "T")]
+    pub trade_order_time: u64,
+
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+
+    #[serde(skip, rename = "I")]
+    pub i_ignore: u64,
+
+    #[serde(skip)]
+    pub w: bool,
+
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+
+    #[serde(skip, rename = "M")]
+    pub m_ignore: bool,
+}
+
+/// Futures order update pushed on the user data stream.
+///
+/// <https://developers.binance.com/docs/derivatives/coin-margined-futures/user-data-streams/Event-Order-Update>
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderTradeUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    /// account alias, COIN-M only
+    #[serde(rename = "i")]
+    pub account_alias: Option<String>,
+
+    #[serde(rename = "o")]
+    pub order: FuturesOrderUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesOrderUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+
+    #[serde(rename = "S")]
+    pub side: String,
+
+    #[serde(rename = "o")]
+    pub order_type: String,
+
+    #[serde(rename = "f")]
+    pub time_in_force: String,
+
+    /// contracts for COIN-M
+    #[serde(rename = "q")]
+    pub original_quantity: String,
+
+    #[serde(rename = "p")]
+    pub original_price: String,
+
+    #[serde(rename = "ap")]
+    pub average_price: String,
+
+    #[serde(rename = "sp")]
+    pub stop_price: String,
+
+    #[serde(rename = "x")]
+    pub execution_type: String,
+
+    #[serde(rename = "X")]
+    pub order_status: String,
+
+    #[serde(rename = "i")]
+    pub order_id: u64,
+
+    #[serde(rename = "l")]
+    pub order_last_filled_quantity: String,
+
+    #[serde(rename = "z")]
+    pub order_filled_accumulated_quantity: String,
+
+    #[serde(rename = "L")]
+    pub last_filled_price: String,
+
+    /// COIN-M only
+    #[serde(rename = "ma")]
+    pub margin_asset: Option<String>,
+
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+
+    #[serde(rename = "n")]
+    pub commission: Option<String>,
+
+    #[serde(rename = "T")]
+    pub order_trade_time: u64,
+
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+
+    #[serde(rename = "b")]
+    pub bids_notional: String,
+
+    #[serde(rename = "a")]
+    pub ask_notional: String,
+
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+
+    #[serde(rename = "R")]
+    pub is_reduce_only: bool,
+
+    #[serde(rename = "wt")]
+    pub working_type: String,
+
+    #[serde(rename = "ot")]
+    pub original_order_type: String,
+
+    #[serde(rename = "ps")]
+    pub position_side: String,
+
+    #[serde(rename = "cp")]
+    pub close_all: bool,
+
+    #[serde(rename = "AP")]
+    pub activation_price: Option<String>,
+
+    #[serde(rename = "cr")]
+    pub callback_rate: Option<String>,
+
+    #[serde(rename = "rp")]
+    pub realized_profit: String,
+
+    #[serde(rename = "pm")]
+    pub price_match: Option<String>,
+}
+
+/// Leverage (`ac`) or Multi-Assets mode (`ai`) change pushed on the futures user data stream.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountConfigUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+
+    #[serde(rename = "ac")]
+    pub leverage: Option<LeverageConfigUpdate>,
+
+    #[serde(rename = "ai")]
+    pub multi_assets: Option<MultiAssetsConfigUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LeverageConfigUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "l")]
+    pub leverage: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MultiAssetsConfigUpdate {
+    #[serde(rename = "j")]
+    pub multi_assets_mode: bool,
+}
+
+/// Options order update, unlike futures `o` is a list of orders.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsOrderTradeUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "o")]
+    pub orders: Vec<OptionsOrderUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsOrderUpdate {
+    #[serde(rename = "T")]
+    pub create_time: u64,
+
+    #[serde(rename = "t")]
+    pub update_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+
+    #[serde(rename = "oid")]
+    pub order_id: String,
+
+    #[serde(rename = "p")]
+    pub price: String,
+
+    #[serde(rename = "q")]
+    pub quantity: String,
+
+    #[serde(rename = "r")]
+    pub reduce_only: bool,
+
+    #[serde(rename = "po")]
+    pub post_only: bool,
+
+    #[serde(rename = "S")]
+    pub status: String,
+
+    #[serde(rename = "e")]
+    pub executed_qty: String,
+
+    #[serde(rename = "ec")]
+    pub executed_cost: String,
+
+    #[serde(rename = "f")]
+    pub fee: String,
+
+    #[serde(rename = "tif")]
+    pub time_in_force: String,
+
+    #[serde(rename = "oty")]
+    pub order_type: String,
+
+    #[serde(rename = "fi", default)]
+    pub fills: Vec<OptionsFill>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsFill {
+    #[serde(rename = "t")]
+    pub trade_id: String,
+
+    #[serde(rename = "p")]
+    pub price: String,
+
+    #[serde(rename = "q")]
+    pub quantity: String,
+
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+
+    /// MAKER or TAKER
+    #[serde(rename = "m")]
+    pub liquidity: String,
+
+    #[serde(rename = "f")]
+    pub fee: String,
+}
+
+/// Options account update with balances, greeks per underlying and
+/// positions.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsAccountUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "B")]
+    pub balances: Vec<OptionsEventBalance>,
+
+    #[serde(rename = "G")]
+    pub greeks: Vec<OptionsEventGreek>,
+
+    #[serde(rename = "P", default)]
+    pub positions: Vec<OptionsEventPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsEventBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "b")]
+    pub balance: String,
+
+    #[serde(rename = "m")]
+    pub margin_balance: String,
+
+    #[serde(rename = "u")]
+    pub unrealized_pnl: String,
+
+    #[serde(rename = "M")]
+    pub maintenance_margin: String,
+
+    #[serde(rename = "i")]
+    pub initial_margin: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsEventGreek {
+    #[serde(rename = "ui")]
+    pub underlying: String,
+
+    #[serde(rename = "d")]
+    pub delta: f64,
+
+    #[serde(rename = "t")]
+    pub theta: f64,
+
+    #[serde(rename = "g")]
+    pub gamma: f64,
+
+    #[serde(rename = "v")]
+    pub vega: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsEventPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c")]
+    pub quantity: String,
+
+    #[serde(rename = "r")]
+    pub reducible_qty: String,
+
+    #[serde(rename = "p")]
+    pub position_value: String,
+
+    #[serde(rename = "a")]
+    pub average_price: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginCallEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    /// only pushed with crossed positions
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: Option<String>,
+
+    #[serde(rename = "p")]
+    pub positions: Vec<MarginCallPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginCallPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "ps")]
+    pub position_side: String,
 
-    #[serde(rename = "T")]
-    pub trade_order_time: u64,
+    #[serde(rename = "pa")]
+    pub position_amount: String,
 
-    #[serde(rename = "t")]
-    pub trade_id: i64,
+    #[serde(rename = "mt")]
+    pub margin_type: String,
 
-    #[serde(skip, rename = "I")]
-    pub i_ignore: u64,
+    #[serde(rename = "iw")]
+    pub isolated_wallet: String,
 
-    #[serde(skip)]
-    pub w: bool,
+    #[serde(rename = "mp")]
+    pub mark_price: String,
 
-    #[serde(rename = "m")]
-    pub is_buyer_maker: bool,
+    #[serde(rename = "up")]
+    pub unrealized_pnl: String,
 
-    #[serde(skip, rename = "M")]
-    pub m_ignore: bool,
+    #[serde(rename = "mm")]
+    pub maintenance_margin_required: String,
 }
 
 /// The Aggregate Trade Streams push trade information that is aggregated for a single taker order.
@@ -767,29 +1504,34 @@ pub struct IndexPriceEvent {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MarkPriceEvent {
-    #[serde(rename = "E")]
+    #[serde(rename = "E", alias = "time")]
     pub event_time: u64,
 
-    #[serde(rename = "P")]
+    #[serde(rename = "P", alias = "estimatedSettlePrice")]
     pub estimate_settle_price: String,
 
-    #[serde(rename = "T")]
+    #[serde(rename = "T", alias = "nextFundingTime")]
     pub next_funding_time: u64,
 
-    #[serde(rename = "e")]
+    /// empty when returned by the premiumIndex REST endpoint
+    #[serde(rename = "e", default)]
     pub event_type: String,
 
-    #[serde(rename = "i")]
+    #[serde(rename = "i", alias = "indexPrice")]
     pub index_price: Option<String>,
 
-    #[serde(rename = "p")]
+    #[serde(rename = "p", alias = "markPrice")]
     pub mark_price: String,
 
-    #[serde(rename = "r")]
+    #[serde(rename = "r", alias = "lastFundingRate")]
     pub funding_rate: String,
 
-    #[serde(rename = "s")]
+    #[serde(rename = "s", alias = "symbol")]
     pub symbol: String,
+
+    /// only returned by the premiumIndex REST endpoint
+    #[serde(rename = "interestRate", default)]
+    pub interest_rate: Option<String>,
 }
 
 // Object({"E": Number(1626118018407), "e": String("forceOrder"), "o": Object({"S": String("SELL"), "T": Number(1626118018404), "X": String("FILLED"), "ap": String("33028.07"), "f": String("IOC"), "l": String("0.010"), "o": String("LIMIT"), "p": String("32896.00"), "q": String("0.010"), "s": String("BTCUSDT"), "z": String("0.010")})})
@@ -1081,63 +1823,114 @@ pub struct IndexKlineEvent {
     pub kline: IndexKline,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// A kline row as returned by /api/v3/klines and /api/v3/uiKlines.
+///
+/// Binance sends every kline as a positional array with the prices and
+/// volumes encoded as strings, so it is deserialized by hand.
+#[derive(Debug, Serialize, Clone, PartialEq)]
 pub struct KlineSummary {
     pub open_time: i64,
 
-    pub open: String,
+    pub open: f64,
 
-    pub high: String,
+    pub high: f64,
 
-    pub low: String,
+    pub low: f64,
 
-    pub close: String,
+    pub close: f64,
 
-    pub volume: String,
+    pub volume: f64,
 
     pub close_time: i64,
 
-    pub quote_asset_volume: String,
+    pub quote_asset_volume: f64,
 
     pub number_of_trades: i64,
 
-    pub taker_buy_base_asset_volume: String,
+    pub taker_buy_base_asset_volume: f64,
 
-    pub taker_buy_quote_asset_volume: String,
+    pub taker_buy_quote_asset_volume: f64,
 }
 
-fn get_value(row: &[Value], index: usize, name: &'static str) -> StdResult<Value, BinanceApiError> {
-    Ok(row
-        .get(index)
-        .ok_or_else(|| BinanceApiError::KlineValueMissingError(index, name))?
-        .clone())
+const KLINE_SUMMARY_FIELDS: [&str; 11] = [
+    "open_time",
+    "open",
+    "high",
+    "low",
+    "close",
+    "volume",
+    "close_time",
+    "quote_asset_volume",
+    "number_of_trades",
+    "taker_buy_base_asset_volume",
+    "taker_buy_quote_asset_volume",
+];
+
+impl<'de> Deserialize<'de> for KlineSummary {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{self, IgnoredAny, SeqAccess, Visitor};
+
+        #[derive(Deserialize)]
+        struct StringNumber(#[serde(with = "string_or_float")] f64);
+
+        struct KlineSummaryVisitor;
+
+        impl<'de> Visitor<'de> for KlineSummaryVisitor {
+            type Value = KlineSummary;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a kline array")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> StdResult<KlineSummary, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                fn next<'de, A, T>(seq: &mut A, index: usize) -> StdResult<T, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                    T: Deserialize<'de>,
+                {
+                    seq.next_element()?.ok_or_else(|| {
+                        de::Error::custom(BinanceApiError::KlineValueMissingError(
+                            index,
+                            KLINE_SUMMARY_FIELDS[index],
+                        ))
+                    })
+                }
+
+                let kline = KlineSummary {
+                    open_time: next(&mut seq, 0)?,
+                    open: next::<_, StringNumber>(&mut seq, 1)?.0,
+                    high: next::<_, StringNumber>(&mut seq, 2)?.0,
+                    low: next::<_, StringNumber>(&mut seq, 3)?.0,
+                    close: next::<_, StringNumber>(&mut seq, 4)?.0,
+                    volume: next::<_, StringNumber>(&mut seq, 5)?.0,
+                    close_time: next(&mut seq, 6)?,
+                    quote_asset_volume: next::<_, StringNumber>(&mut seq, 7)?.0,
+                    number_of_trades: next(&mut seq, 8)?,
+                    taker_buy_base_asset_volume: next::<_, StringNumber>(&mut seq, 9)?.0,
+                    taker_buy_quote_asset_volume: next::<_, StringNumber>(&mut seq, 10)?.0,
+                };
+                // the trailing "unused field" and anything Binance may append later
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+
+                Ok(kline)
+            }
+        }
+
+        deserializer.deserialize_seq(KlineSummaryVisitor)
+    }
 }
 
 impl TryFrom<Vec<Value>> for KlineSummary {
     type Error = BinanceApiError;
 
     fn try_from(row: Vec<Value>) -> Result<Self, Self::Error> {
-        Ok(Self {
-            open_time: from_value(get_value(&row, 0, "open_time")?)?,
-            open: from_value(get_value(&row, 1, "open")?)?,
-            high: from_value(get_value(&row, 2, "high")?)?,
-            low: from_value(get_value(&row, 3, "low")?)?,
-            close: from_value(get_value(&row, 4, "close")?)?,
-            volume: from_value(get_value(&row, 5, "volume")?)?,
-            close_time: from_value(get_value(&row, 6, "close_time")?)?,
-            quote_asset_volume: from_value(get_value(&row, 7, "quote_asset_volume")?)?,
-            number_of_trades: from_value(get_value(&row, 8, "number_of_trades")?)?,
-            taker_buy_base_asset_volume: from_value(get_value(
-                &row,
-                9,
-                "taker_buy_base_asset_volume",
-            )?)?,
-            taker_buy_quote_asset_volume: from_value(get_value(
-                &row,
-                10,
-                "taker_buy_quote_asset_volume",
-            )?)?,
-        })
+        Ok(from_value(Value::Array(row))?)
     }
 }
 
@@ -1469,6 +2262,263 @@ pub struct CrossMarginFee {
     pub marginable_pairs: Vec<String>,
 }
 
+/// Margin order result; `margin_buy_borrow_*` is only set when the order
+/// borrowed through its side effect.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransaction {
+    #[serde(flatten)]
+    pub transaction: Transaction,
+    #[serde(default)]
+    pub is_isolated: bool,
+    pub margin_buy_borrow_amount: Option<String>,
+    pub margin_buy_borrow_asset: Option<String>,
+}
+
+/// Paged response of the margin history endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginRows<T> {
+    #[serde(default = "Vec::new")]
+    pub rows: Vec<T>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecord {
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    pub isolated_symbol: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    #[serde(with = "string_or_float_opt", default)]
+    pub interest: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub principal: f64,
+    pub status: String,
+    pub timestamp: u64,
+    pub tx_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossMarginAccount {
+    pub created: bool,
+    pub borrow_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub margin_level: f64,
+    #[serde(with = "string_or_float_opt", default)]
+    pub collateral_margin_level: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+    #[serde(
+        rename = "TotalCollateralValueInUSDT",
+        with = "string_or_float_opt",
+        default
+    )]
+    pub total_collateral_value_in_usdt: Option<f64>,
+    #[serde(
+        rename = "totalOpenOrderLossInUSDT",
+        with = "string_or_float_opt",
+        default
+    )]
+    pub total_open_order_loss_in_usdt: Option<f64>,
+    pub trade_enabled: bool,
+    pub transfer_in_enabled: bool,
+    pub transfer_out_enabled: bool,
+    pub account_type: Option<String>,
+    pub user_assets: Vec<MarginAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccount {
+    pub assets: Vec<IsolatedMarginPair>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub total_asset_of_btc: Option<f64>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub total_liability_of_btc: Option<f64>,
+    #[serde(with = "string_or_float_opt", default)]
+    pub total_net_asset_of_btc: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    pub symbol: String,
+    pub base_asset: IsolatedMarginAsset,
+    pub quote_asset: IsolatedMarginAsset,
+    pub isolated_created: bool,
+    pub enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub margin_level: f64,
+    pub margin_level_status: String,
+    #[serde(with = "string_or_float")]
+    pub margin_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidate_price: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidate_rate: f64,
+    pub trade_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAsset {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset_of_btc: f64,
+    pub repay_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub total_asset: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowable {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub borrow_limit: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferable {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginInterest {
+    pub tx_id: u64,
+    pub interest_accured_time: u64,
+    pub asset: String,
+    pub raw_asset: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub principal: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub interest_rate: f64,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub isolated_symbol: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceLiquidationRecord {
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    pub side: String,
+    pub symbol: String,
+    pub time_in_force: String,
+    pub is_isolated: bool,
+    pub updated_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedAccountStatus {
+    pub success: bool,
+    pub symbol: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedAccountLimit {
+    pub enabled_account: u32,
+    pub max_account: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransfer {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub status: String,
+    pub timestamp: u64,
+    pub tx_id: u64,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub trans_from: Option<String>,
+    pub trans_to: Option<String>,
+    pub from_symbol: Option<String>,
+    pub to_symbol: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginTier {
+    pub symbol: String,
+    pub tier: u32,
+    #[serde(with = "string_or_float")]
+    pub effective_multiple: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_risk_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_risk_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub base_asset_max_borrowable: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_asset_max_borrowable: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestRate {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub daily_interest_rate: f64,
+    pub timestamp: u64,
+    pub vip_level: i32,
+}
+
 pub(crate) mod string_or_float {
     use std::fmt;
 
@@ -1634,3 +2684,254 @@ fn test_account_update_event() {
     assert_eq!(format!("{:?}", v), res);
     //let event =  from_value::<AccountUpdateEvent>(json).unwrap();
 }
+
+#[test]
+fn test_account_commission() {
+    let json = r#"{"symbol":"BTCUSDT","standardCommission":{"maker":"0.00000010","taker":"0.00000020","buyer":"0.00000030","seller":"0.00000040"},"taxCommission":{"maker":"0.00000112","taker":"0.00000114","buyer":"0.00000118","seller":"0.00000116"},"discount":{"enabledForAccount":true,"enabledForSymbol":true,"discountAsset":"BNB","discount":"0.75000000"}}"#;
+    let v: AccountCommission = serde_json::from_str(json).unwrap();
+    assert_eq!(v.standard_commission.buyer, 0.0000003);
+    assert_eq!(v.tax_commission.taker, 0.00000114);
+    let missing = json.replace(r#","seller":"0.00000040""#, "");
+    assert!(serde_json::from_str::<AccountCommission>(&missing).is_err());
+
+    let json = r#"{"standardCommissionForOrder":{"maker":"0.00000112","taker":"0.00000114"},"taxCommissionForOrder":{"maker":"0.00000112","taker":"0.00000114"},"discount":{"enabledForAccount":true,"enabledForSymbol":true,"discountAsset":"BNB","discount":"0.25000000"}}"#;
+    let v: OrderTestCommission = serde_json::from_str(json).unwrap();
+    assert_eq!(v.standard_commission_for_order.unwrap().buyer, None);
+
+    let json = r#"[{"symbol":"BTCUSDT","preventedMatchId":1,"takerOrderId":5,"makerSymbol":"BTCUSDT","makerOrderId":3,"tradeGroupId":1,"selfTradePreventionMode":"EXPIRE_MAKER","price":"1.100000","makerPreventedQuantity":"1.300000","transactTime":1669101687094}]"#;
+    let v: Vec<PreventedMatch> = serde_json::from_str(json).unwrap();
+    assert_eq!(v[0].price, 1.1);
+    assert_eq!(v[0].maker_prevented_quantity, 1.3);
+}
+
+#[test]
+fn test_order_list() {
+    let json = r#"
+    {
+  "orderListId": 1,
+  "contingencyType": "OCO",
+  "listStatusType": "EXEC_STARTED",
+  "listOrderStatus": "EXECUTING",
+  "listClientOrderId": "lH1YDkuQKWiXVXHPSKYEIp",
+  "transactionTime": 1710485608839,
+  "symbol": "LTCBTC",
+  "orders": [
+    {
+      "symbol": "LTCBTC",
+      "orderId": 10,
+      "clientOrderId": "44nZvqpemY7sVYgPYbvPih"
+    },
+    {
+      "symbol": "LTCBTC",
+      "orderId": 11,
+      "clientOrderId": "NuMp0nVYnciDiFmVqfpBqK"
+    }
+  ],
+  "orderReports": [
+    {
+      "symbol": "LTCBTC",
+      "orderId": 10,
+      "orderListId": 1,
+      "clientOrderId": "44nZvqpemY7sVYgPYbvPih",
+      "transactTime": 1710485608839,
+      "price": "1.00000000",
+      "origQty": "5.00000000",
+      "executedQty": "0.00000000",
+      "origQuoteOrderQty": "0.000000",
+      "cummulativeQuoteQty": "0.00000000",
+      "status": "NEW",
+      "timeInForce": "GTC",
+      "type": "STOP_LOSS_LIMIT",
+      "side": "SELL",
+      "stopPrice": "1.00000000",
+      "workingTime": -1,
+      "icebergQty": "1.00000000",
+      "selfTradePreventionMode": "NONE"
+    },
+    {
+      "symbol": "LTCBTC",
+      "orderId": 11,
+      "orderListId": 1,
+      "clientOrderId": "NuMp0nVYnciDiFmVqfpBqK",
+      "transactTime": 1710485608839,
+      "price": "3.00000000",
+      "origQty": "5.00000000",
+      "executedQty": "0.00000000",
+      "origQuoteOrderQty": "0.000000",
+      "cummulativeQuoteQty": "0.00000000",
+      "status": "PARTIALLY_FILLED",
+      "timeInForce": "GTC",
+      "type": "LIMIT_MAKER",
+      "side": "SELL",
+      "workingTime": 1710485608839,
+      "selfTradePreventionMode": "NONE"
+    }
+  ]
+}
+    "#;
+
+    let v: OrderList = serde_json::from_str(json).unwrap();
+    assert_eq!(v.contingency_type, ContingencyType::Oco);
+    assert_eq!(v.list_status_type, ListStatusType::ExecStarted);
+    assert_eq!(v.list_order_status, ListOrderStatus::Executing);
+    assert_eq!(v.orders.len(), 2);
+    assert_eq!(v.order_reports[0].stop_price, 1.0);
+    assert_eq!(v.order_reports[1].stop_price, 0.0);
+    assert_eq!(v.order_reports[1].status, OrderStatus::PartiallyFilled);
+
+    let json = r#"
+    {
+  "orderListId": 1274512,
+  "contingencyType": "OCO",
+  "listStatusType": "ALL_DONE",
+  "listOrderStatus": "ALL_DONE",
+  "listClientOrderId": "08985fedd9ea2cf6b28996",
+  "transactionTime": 1707423690200,
+  "symbol": "BNBUSDT",
+  "orders": [
+    { "symbol": "BNBUSDT", "orderId": 12, "clientOrderId": "a" }
+  ]
+}
+    "#;
+    let v: OrderList = serde_json::from_str(json).unwrap();
+    assert!(v.order_reports.is_empty());
+}
+
+#[test]
+fn test_sor_transaction() {
+    let json = r#"
+    {
+  "symbol": "BTCUSDT",
+  "orderId": 2,
+  "orderListId": -1,
+  "clientOrderId": "sBI1KM6nNtOfj5tccZSKly",
+  "transactTime": 1689149087774,
+  "price": "31000.00000000",
+  "origQty": "0.50000000",
+  "executedQty": "0.50000000",
+  "origQuoteOrderQty": "0.000000",
+  "cummulativeQuoteQty": "14000.00000000",
+  "status": "FILLED",
+  "timeInForce": "GTC",
+  "type": "LIMIT",
+  "side": "BUY",
+  "workingTime": 1689149087774,
+  "fills": [
+    {
+      "matchType": "ONE_PARTY_TRADE_REPORT",
+      "price": "28000.00000000",
+      "qty": "0.50000000",
+      "commission": "0.00000000",
+      "commissionAsset": "BTC",
+      "tradeId": -1,
+      "allocId": 0
+    }
+  ],
+  "workingFloor": "SOR",
+  "selfTradePreventionMode": "NONE",
+  "usedSor": true
+}
+    "#;
+
+    let v: SorTransaction = serde_json::from_str(json).unwrap();
+    assert_eq!(v.working_floor, Some(WorkingFloor::Sor));
+    assert!(v.used_sor);
+    assert_eq!(v.fills[0].alloc_id, 0);
+    assert_eq!(v.fills[0].price, 28000.0);
+}
+
+#[test]
+fn test_kline_summary() {
+    let json = r#"
+    [
+  [
+    1499040000000,
+    "0.01634790",
+    "0.80000000",
+    "0.01575800",
+    "0.01577100",
+    "148976.11427815",
+    1499644799999,
+    "2434.19055334",
+    308,
+    "1756.87402397",
+    "28.46694368",
+    "0"
+  ]
+]
+    "#;
+
+    let v: Vec<KlineSummary> = serde_json::from_str(json).unwrap();
+    assert_eq!(v[0].open_time, 1499040000000);
+    assert_eq!(v[0].high, 0.8);
+    assert_eq!(v[0].number_of_trades, 308);
+    assert_eq!(v[0].taker_buy_quote_asset_volume, 28.46694368);
+
+    let row: Vec<Value> =
+        serde_json::from_str(r#"[1499040000000, "0.01634790", "0.80000000"]"#).unwrap();
+    let err = KlineSummary::try_from(row).unwrap_err();
+    assert!(err.to_string().contains("low"));
+}
+
+#[test]
+fn test_futures_stream_events() {
+    use crate::ws_streams::stream_events::BinanceStreamEvent;
+
+    let json = r#"{"e":"ORDER_TRADE_UPDATE","E":1591274595442,"T":1591274595453,"i":"SfsR","o":{"s":"BTCUSD_200925","c":"TEST","S":"SELL","o":"TRAILING_STOP_MARKET","f":"GTC","q":"2","p":"0","ap":"0","sp":"9103.1","x":"NEW","X":"NEW","i":8888888,"l":"0","z":"0","L":"0","ma":"BTC","N":"BTC","n":"0","T":1591274595442,"t":0,"rp":"0","b":"0","a":"0.02","m":false,"R":false,"wt":"CONTRACT_PRICE","ot":"TRAILING_STOP_MARKET","ps":"LONG","cp":false,"AP":"9476.8","cr":"5.0","pP":false}}"#;
+    let event: BinanceStreamEvent = serde_json::from_str(json).unwrap();
+    assert!(
+        matches!(event, BinanceStreamEvent::OrderTradeUpdate(e) if e.order.margin_asset.as_deref() == Some("BTC"))
+    );
+
+    let json = r#"{"e":"forceOrder","E":1568014460893,"o":{"s":"BTCUSD_200925","ps":"BTCUSD","S":"SELL","o":"LIMIT","f":"IOC","q":"1","p":"9425.5","ap":"9496.5","X":"FILLED","l":"1","z":"1","T":1591154240949}}"#;
+    let event: BinanceStreamEvent = serde_json::from_str(json).unwrap();
+    assert!(matches!(event, BinanceStreamEvent::Liquidation(_)));
+
+    let json = r#"{"e":"markPriceUpdate","E":1596095725000,"s":"BTCUSD_201225","p":"10934.62615417","P":"10962.17178236","i":"10933.62615417","r":"","T":0}"#;
+    let event: BinanceStreamEvent = serde_json::from_str(json).unwrap();
+    assert!(matches!(event, BinanceStreamEvent::MarkPrice(_)));
+
+    let json = r#"{"e":"indexPriceUpdate","E":1591261236000,"i":"BTCUSD","p":"9636.57860000"}"#;
+    let event: BinanceStreamEvent = serde_json::from_str(json).unwrap();
+    assert!(matches!(event, BinanceStreamEvent::IndexPrice(_)));
+
+    let json = r#"{"e":"ACCOUNT_CONFIG_UPDATE","E":1611646737479,"T":1611646737476,"ac":{"s":"BTCUSD_PERP","l":25}}"#;
+    let event: BinanceStreamEvent = serde_json::from_str(json).unwrap();
+    assert!(matches!(event, BinanceStreamEvent::AccountConfigUpdate(e) if e.leverage.is_some()));
+
+    let json = r#"{"e":"ORDER_TRADE_UPDATE","E":1657613775883,"o":[{"T":1657613342918,"t":1657613342918,"s":"BTC-220930-18000-C","c":"","oid":"4611869636869226548","p":"1993","q":"1","stp":0,"r":false,"po":true,"S":"PARTIALLY_FILLED","e":"0.1","ec":"199.3","f":"2","tif":"GTC","oty":"LIMIT","fi":[{"t":"20","p":"1993","q":"0.1","T":1657613774336,"m":"TAKER","f":"0.0002"}]}]}"#;
+    let event: BinanceStreamEvent = serde_json::from_str(json).unwrap();
+    assert!(
+        matches!(event, BinanceStreamEvent::OptionsOrderTradeUpdate(e) if e.orders[0].fills.len() == 1)
+    );
+
+    let json = r#"{"e":"ACCOUNT_UPDATE","E":1591261134288,"B":[{"b":"100000","m":"99999.99999999","u":"-0.00000001","U":-1,"M":"0","i":"0","a":"USDT"}],"G":[{"ui":"SOLUSDT","d":-33.2933905,"t":35.5520529,"g":-13.206491,"v":-1830.7859}],"P":[{"s":"SOL-220912-35-C","c":"-50","r":"-50","p":"-100","a":"32.019355"}],"uid":1000006559949}"#;
+    let event: BinanceStreamEvent = serde_json::from_str(json).unwrap();
+    assert!(matches!(event, BinanceStreamEvent::OptionsAccountUpdate(e) if e.greeks.len() == 1));
+
+    let json = r#"{"e":"listenKeyExpired","E":1576653824250}"#;
+    let event: BinanceStreamEvent = serde_json::from_str(json).unwrap();
+    assert!(matches!(
+        event,
+        BinanceStreamEvent::UserDataStreamExpired(_)
+    ));
+}
+
+#[test]
+fn test_margin_models() {
+    let json = r#"{"symbol":"BTCUSDT","orderId":28,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","transactTime":1507725176595,"price":"1.00000000","origQty":"10.00000000","executedQty":"10.00000000","cummulativeQuoteQty":"10.00000000","status":"FILLED","timeInForce":"GTC","type":"MARKET","isIsolated":true,"side":"SELL","marginBuyBorrowAmount":"5","marginBuyBorrowAsset":"BTC","fills":[]}"#;
+    let transaction: MarginTransaction = serde_json::from_str(json).unwrap();
+    assert!(transaction.is_isolated);
+    assert_eq!(transaction.transaction.order_id, 28);
+    assert_eq!(transaction.margin_buy_borrow_asset.as_deref(), Some("BTC"));
+
+    let json = r#"{"created":true,"borrowEnabled":true,"marginLevel":"11.64405625","collateralMarginLevel":"3.2","totalAssetOfBtc":"6.82728457","totalLiabilityOfBtc":"0.58633215","totalNetAssetOfBtc":"6.24095242","TotalCollateralValueInUSDT":"5.82728457","totalOpenOrderLossInUSDT":"582.728457","tradeEnabled":true,"transferInEnabled":true,"transferOutEnabled":true,"accountType":"MARGIN_1","userAssets":[{"asset":"BTC","borrowed":"0.00000000","free":"0.00499500","interest":"0.00000000","locked":"0.00000000","netAsset":"0.00499500"}]}"#;
+    let account: CrossMarginAccount = serde_json::from_str(json).unwrap();
+    assert_eq!(account.total_collateral_value_in_usdt, Some(5.82728457));
+    assert_eq!(account.user_assets[0].free, 0.004995);
+
+    let json = r#"{"rows":[{"txId":1352286576452864727,"interestAccuredTime":1672160400000,"asset":"USDT","rawAsset":"USDT","principal":"45.3313","interest":"0.00024995","interestRate":"0.00013233","type":"ON_BORROW","isolatedSymbol":"BNBUSDT"}],"total":1}"#;
+    let rows: MarginRows<MarginInterest> = serde_json::from_str(json).unwrap();
+    assert_eq!(rows.total, 1);
+    assert_eq!(rows.rows[0].type_name, "ON_BORROW");
+}
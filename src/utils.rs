@@ -47,7 +47,51 @@ pub fn to_f64(v: &Value) -> f64 {
     v.as_str().unwrap().parse().unwrap()
 }
 
+/// Rounds `amount` down to `decimals` decimals. Amounts already at that
+/// precision are kept as is, e.g. 0.29 * 100 is 28.999999999999996 and must
+/// not become 0.28.
+pub(crate) fn floor_to_decimals(amount: f64, decimals: u8) -> f64 {
+    round_to_decimals(amount, decimals, f64::floor)
+}
+
+/// Rounds `amount` up to `decimals` decimals, see `floor_to_decimals`.
+pub(crate) fn ceil_to_decimals(amount: f64, decimals: u8) -> f64 {
+    round_to_decimals(amount, decimals, f64::ceil)
+}
+
+fn round_to_decimals(amount: f64, decimals: u8, round: fn(f64) -> f64) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    let scaled = amount * scale;
+    let nearest = scaled.round();
+    // allow a few ulps of error from the multiplication
+    if (scaled - nearest).abs() <= scaled.abs() * 8.0 * f64::EPSILON {
+        nearest / scale
+    } else {
+        round(scaled) / scale
+    }
+}
+
 fn get_timestamp(start: SystemTime) -> Result<u64> {
     let since_epoch = start.duration_since(UNIX_EPOCH)?;
     Ok(since_epoch.as_secs() * 1000 + u64::from(since_epoch.subsec_nanos()) / 1_000_000)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_to_decimals() {
+        assert_eq!(floor_to_decimals(1.23456789, 4), 1.2345);
+        assert_eq!(floor_to_decimals(0.00009, 4), 0.0);
+        assert_eq!(floor_to_decimals(0.29, 2), 0.29);
+        assert_eq!(floor_to_decimals(1.13, 2), 1.13);
+        assert_eq!(floor_to_decimals(1.005, 3), 1.005);
+        assert_eq!(floor_to_decimals(1.1399, 2), 1.13);
+
+        assert_eq!(ceil_to_decimals(1.000000001, 8), 1.00000001);
+        assert_eq!(ceil_to_decimals(0.29, 8), 0.29);
+        assert_eq!(ceil_to_decimals(1.13, 2), 1.13);
+        assert_eq!(ceil_to_decimals(1.1301, 2), 1.14);
+    }
+}
@@ -2,6 +2,8 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+use crate::endpoints::{spot_market::KlineInterval, usd_m_futures::ContractType};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum PartialBookDepthLevel {
     Five,
@@ -25,13 +27,25 @@ pub enum MarketStreams {
     PartialBookDepth1s(String, PartialBookDepthLevel),
     PartialBookDepth100ms(String, PartialBookDepthLevel),
     TradeStream(String),
+    // futures streams
+    AggTrade(String),
+    Kline(String, KlineInterval),
+    /// pair, contract type and interval
+    ContinuousKline(String, ContractType, KlineInterval),
+    IndexPrice1s(String),
+    IndexPriceKline(String, KlineInterval),
+    /// symbol, or pair for all the COIN-M symbols of the pair
+    MarkPrice1s(String),
+    MarkPriceKline(String, KlineInterval),
+    BookTicker(String),
+    Liquidation(String),
 }
 
-impl Into<String> for &MarketStreams {
-    fn into(self) -> String {
+impl From<&MarketStreams> for String {
+    fn from(stream: &MarketStreams) -> Self {
         use MarketStreams::*;
 
-        match self {
+        match stream {
             DiffDepth1s(s) => format!("{}@depth", s.to_lowercase()),
             DiffDepth100ms(s) => format!("{}@depth@100ms", s.to_lowercase()),
             PartialBookDepth1s(s, l) => format!("{}@depth{}", s.to_lowercase(), l),
@@ -41,6 +55,23 @@ impl Into<String> for &MarketStreams {
             TradeStream(s) => {
                 format!("{}@trade", s.to_lowercase())
             }
+            AggTrade(s) => format!("{}@aggTrade", s.to_lowercase()),
+            Kline(s, i) => format!("{}@kline_{}", s.to_lowercase(), i.as_str()),
+            ContinuousKline(p, ct, i) => {
+                let ct = serde_json::to_value(ct).unwrap_or_default();
+                format!(
+                    "{}_{}@continuousKline_{}",
+                    p.to_lowercase(),
+                    ct.as_str().unwrap_or_default().to_lowercase(),
+                    i.as_str()
+                )
+            }
+            IndexPrice1s(p) => format!("{}@indexPrice@1s", p.to_lowercase()),
+            IndexPriceKline(p, i) => format!("{}@indexPriceKline_{}", p.to_lowercase(), i.as_str()),
+            MarkPrice1s(s) => format!("{}@markPrice@1s", s.to_lowercase()),
+            MarkPriceKline(s, i) => format!("{}@markPriceKline_{}", s.to_lowercase(), i.as_str()),
+            BookTicker(s) => format!("{}@bookTicker", s.to_lowercase()),
+            Liquidation(s) => format!("{}@forceOrder", s.to_lowercase()),
         }
     }
 }
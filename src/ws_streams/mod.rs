@@ -16,6 +16,7 @@ use crate::{api_config::ApiConfig, errors::BinanceApiError};
 use market_streams::MarketStreams;
 use stream_events::BinanceStreamEvent;
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum StreamEvent {
@@ -66,7 +67,7 @@ impl WssConnection {
         let url = Url::parse(wss).expect("wrong wss url");
         match connect_async(url.as_str()).await {
             Ok(answer) => Ok(Self(answer.0)),
-            Err(e) => Err(BinanceApiError::WsHandShake(e)),
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -76,6 +77,12 @@ impl WssConnection {
     }
 }
 
+/// Endpoints in `ApiConfig` are bare hosts (plus a market prefix such as
+/// `/pm`), the `/ws` path is added here.
+fn raw_stream_url(ws_endpoint: &str, name: &str) -> String {
+    format!("{}/ws/{}", ws_endpoint, name)
+}
+
 #[allow(dead_code)]
 pub struct RawStream {
     name: MarketStreams,
@@ -86,15 +93,20 @@ impl RawStream {
     pub async fn new(
         config: &ApiConfig,
         market_stream: MarketStreams,
+    ) -> Result<Self, BinanceApiError> {
+        Self::with_endpoint(&config.ws_endpoint, market_stream).await
+    }
+
+    /// Connects to another market, e.g. `config.coin_m_ws_endpoint`.
+    pub async fn with_endpoint(
+        ws_endpoint: &str,
+        market_stream: MarketStreams,
     ) -> Result<Self, BinanceApiError> {
         let stream_name: String = (&market_stream).into();
         Ok(Self {
             name: market_stream,
-            connection: WssConnection::connect_wss(&format!(
-                "{}/ws/{}",
-                config.ws_endpoint, stream_name
-            ))
-            .await?,
+            connection: WssConnection::connect_wss(&raw_stream_url(ws_endpoint, &stream_name))
+                .await?,
         })
     }
 }
@@ -139,6 +151,99 @@ impl Stream for RawStream {
     }
 }
 
+/// User data stream of a listen key, the key must be kept alive through the
+/// REST API of the same market.
+pub struct UserStream {
+    connection: WssConnection,
+}
+
+impl UserStream {
+    pub async fn new(ws_endpoint: &str, listen_key: &str) -> Result<Self, BinanceApiError> {
+        Ok(Self {
+            connection: WssConnection::connect_wss(&raw_stream_url(ws_endpoint, listen_key))
+                .await?,
+        })
+    }
+
+    pub async fn disconnect(mut self) -> Result<(), BinanceApiError> {
+        self.connection.disconnect().await
+    }
+}
+
+/// User data events that aren't modelled, these are skipped rather than
+/// reported as a broken stream.
+const UNMODELLED_USER_EVENTS: &[&str] = &[
+    "TRADE_LITE",
+    "STRATEGY_UPDATE",
+    "GRID_UPDATE",
+    "CONDITIONAL_ORDER_TRIGGER_REJECT",
+    "CONDITIONAL_ORDER_TRADE_UPDATE",
+    "RISK_LEVEL_CHANGE",
+    "riskLevelChange",
+    "openOrderLoss",
+    "liabilityChange",
+    "listStatus",
+    "externalLockUpdate",
+    "eventStreamTerminated",
+];
+
+/// Decodes a user data stream frame, `None` for frames that are skipped. A
+/// modelled event that fails to parse yields `Reconnect` so that the caller
+/// resyncs through the REST API instead of missing an order or balance update.
+fn decode_user_event(msg: &str) -> Option<BinanceStreamEvent> {
+    let value: serde_json::Value = match serde_json::from_str(msg) {
+        Ok(value) => value,
+        Err(_) => return Some(BinanceStreamEvent::Reconnect),
+    };
+    // pings
+    if !value.is_object() {
+        return None;
+    }
+    let event_type = match value.get("e").and_then(|e| e.as_str()) {
+        Some(event_type) => event_type,
+        None => return Some(BinanceStreamEvent::Reconnect),
+    };
+    if UNMODELLED_USER_EVENTS.contains(&event_type) {
+        return None;
+    }
+    match BinanceStreamEvent::deserialize(&value) {
+        // any payload with `e` and `E` fits the listenKeyExpired variant
+        Ok(BinanceStreamEvent::UserDataStreamExpired(_)) if event_type != "listenKeyExpired" => {
+            Some(BinanceStreamEvent::Reconnect)
+        }
+        Ok(event) => Some(event),
+        Err(_) => Some(BinanceStreamEvent::Reconnect),
+    }
+}
+
+impl Stream for UserStream {
+    type Item = BinanceStreamEvent;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let s = &mut self.get_mut().connection;
+
+        match s.poll_next_unpin(cx) {
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(Err(_))) => Poll::Ready(Some(BinanceStreamEvent::Reconnect)),
+            Poll::Ready(Some(Ok(x))) => match x.to_text() {
+                Ok(msg) => match decode_user_event(msg) {
+                    Some(event) => Poll::Ready(Some(event)),
+                    None => {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                },
+                // the user data stream only sends text frames
+                Err(_) => Poll::Ready(Some(BinanceStreamEvent::Reconnect)),
+            },
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
 pub struct CombinedStream {
     pub name: Vec<MarketStreams>,
     connection: Option<WssConnection>,
@@ -226,3 +331,84 @@ impl Stream for CombinedStream {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_stream_url() {
+        let mainnet = ApiConfig::default();
+        assert_eq!(
+            raw_stream_url(&mainnet.ws_endpoint, "btcusdt@trade"),
+            "wss://stream.binance.com:443/ws/btcusdt@trade"
+        );
+        assert_eq!(
+            raw_stream_url(&mainnet.futures_ws_endpoint, "btcusdt@aggTrade"),
+            "wss://fstream.binance.com/ws/btcusdt@aggTrade"
+        );
+        assert_eq!(
+            raw_stream_url(&mainnet.coin_m_ws_endpoint, "btcusd_perp@markPrice"),
+            "wss://dstream.binance.com/ws/btcusd_perp@markPrice"
+        );
+        assert_eq!(
+            raw_stream_url(
+                mainnet.portfolio_margin_ws_endpoint.as_deref().unwrap(),
+                "listenKey"
+            ),
+            "wss://fstream.binance.com/pm/ws/listenKey"
+        );
+        assert_eq!(
+            raw_stream_url(mainnet.options_ws_endpoint.as_deref().unwrap(), "listenKey"),
+            "wss://nbstream.binance.com/eoptions/ws/listenKey"
+        );
+
+        let testnet = ApiConfig::testnet();
+        assert_eq!(
+            raw_stream_url(&testnet.ws_endpoint, "btcusdt@trade"),
+            "wss://testnet.binance.vision/ws/btcusdt@trade"
+        );
+        assert_eq!(
+            raw_stream_url(&testnet.futures_ws_endpoint, "btcusdt@aggTrade"),
+            "wss://fstream.binancefuture.com/ws/btcusdt@aggTrade"
+        );
+        assert_eq!(
+            raw_stream_url(&testnet.coin_m_ws_endpoint, "btcusd_perp@markPrice"),
+            "wss://dstream.binancefuture.com/ws/btcusd_perp@markPrice"
+        );
+        assert!(testnet.portfolio_margin_ws_endpoint.is_none());
+        assert!(testnet.options_ws_endpoint.is_none());
+    }
+
+    #[test]
+    fn test_decode_user_event() {
+        assert!(decode_user_event("1700000000000").is_none());
+        assert!(decode_user_event(
+            r#"{"e":"TRADE_LITE","E":1721895408092,"T":1721895408214,"s":"BTCUSDT"}"#
+        )
+        .is_none());
+
+        assert!(matches!(
+            decode_user_event(r#"{"e":"listenKeyExpired","E":1576653824250}"#),
+            Some(BinanceStreamEvent::UserDataStreamExpired(_))
+        ));
+        assert!(matches!(
+            decode_user_event(
+                r#"{"e":"ACCOUNT_CONFIG_UPDATE","E":1611646737479,"T":1611646737476,"ac":{"s":"BTCUSD_PERP","l":25}}"#
+            ),
+            Some(BinanceStreamEvent::AccountConfigUpdate(_))
+        ));
+
+        // a modelled event whose payload changed must not be dropped
+        assert!(matches!(
+            decode_user_event(
+                r#"{"e":"ORDER_TRADE_UPDATE","E":1568879465651,"o":{"s":"BTCUSDT"}}"#
+            ),
+            Some(BinanceStreamEvent::Reconnect)
+        ));
+        assert!(matches!(
+            decode_user_event(r#"{"e":"ACCOUNT_UPDATE","E":1564745798939"#),
+            Some(BinanceStreamEvent::Reconnect)
+        ));
+    }
+}
@@ -22,6 +22,19 @@ pub enum BinanceStreamEvent {
     DepthOrderBook(DepthOrderBookEvent),
     BookTicker(BookTickerEvent),
     // PartialOrderBookDepth(PartialOrderBookDepthEvent),
+    // futures streams, the order matters as the variants are tried in turn
+    OrderTradeUpdate(OrderTradeUpdateEvent),
+    OptionsOrderTradeUpdate(OptionsOrderTradeUpdateEvent),
+    OptionsAccountUpdate(OptionsAccountUpdateEvent),
+    MarkPrice(MarkPriceEvent),
+    MarkPriceAll(Vec<MarkPriceEvent>),
+    IndexPrice(IndexPriceEvent),
+    ContinuousKline(ContinuousKlineEvent),
+    IndexKline(IndexKlineEvent),
+    Liquidation(LiquidationEvent),
+    MarginCall(MarginCallEvent),
+    AccountConfigUpdate(AccountConfigUpdateEvent),
+    UserDataStreamExpired(UserDataStreamExpiredEvent),
     Reconnect,
 }
 